@@ -0,0 +1,134 @@
+use crate::core::{batch::get_several, cache::Cache, endpoint::{take_request, GenerateConstructors}, http::{query, Http}, paginator::{self, fetch_pages, paginate, PagedRequest}};
+use crate::core::structs::{album_structs::{AlbumTracks, FullAlbum, NewReleases, SimplifiedTrack}, artist_structs::Album, paging::Paging};
+use crate::error::Error;
+
+use futures::stream::Stream;
+use serde::{Deserialize, Serialize};
+
+// Get Several Albums only takes 20 ids at a time
+const ALBUM_CHUNK: usize = 20;
+
+#[derive(Debug, Clone)]
+pub struct AlbumData {
+    pub userid: String,
+    cache: Cache<AlbumResult>,
+    http: Http,
+    request: Option<AlbumRequest>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum AlbumResult {
+    Album(Box<FullAlbum>),
+    /// In the same order as the ids that were asked for, None for ids spotify doesn't know
+    Albums(Vec<Option<FullAlbum>>),
+    Tracks(AlbumTracks),
+    NewReleases(Paging<Album>),
+}
+
+// What the next call to run will fetch
+#[derive(Debug, Clone, PartialEq)]
+enum AlbumRequest {
+    Album { id: String, market: Option<String> },
+    Albums { ids: Vec<String>, market: Option<String> },
+    Tracks { id: String, market: Option<String>, limit: Option<i32>, offset: Option<i32>, max_pages: usize },
+    NewReleases { limit: Option<i32>, offset: Option<i32>, max_pages: usize },
+}
+
+impl PagedRequest for AlbumRequest {
+    fn max_pages(&mut self) -> Option<&mut usize> {
+        match self {
+            AlbumRequest::Tracks { max_pages, .. } | AlbumRequest::NewReleases { max_pages, .. } => Some(max_pages),
+            AlbumRequest::Album { .. } | AlbumRequest::Albums { .. } => None,
+        }
+    }
+}
+
+impl AlbumData {
+    GenerateConstructors!(app AlbumData, Cache);
+
+    pub(crate) fn from_http(http: Http, cache: Cache<AlbumResult>) -> AlbumData {
+        AlbumData {
+            userid: http.tokens().client_id().to_owned(),
+            cache,
+            http,
+            request: None,
+        }
+    }
+
+    pub fn get_album(&mut self, albumid: &str, market: Option<&str>) -> &mut Self {
+        self.request = Some(AlbumRequest::Album { id: albumid.to_string(), market: market.map(str::to_string) });
+        self
+    }
+
+    /// Gets any number of albums, they are asked for 20 at a time since that is all spotify allows
+    pub fn get_albums(&mut self, ids: &[&str], market: Option<&str>) -> &mut Self {
+        self.request = Some(AlbumRequest::Albums {
+            ids: ids.iter().map(|id| id.to_string()).collect(),
+            market: market.map(str::to_string),
+        });
+        self
+    }
+
+    /// Gets a page of the album's tracks, add [`all_pages`](AlbumData::all_pages) to get the rest of the pages as well
+    pub fn get_tracks(&mut self, albumid: &str, market: Option<&str>, limit: Option<i32>, offset: Option<i32>) -> &mut Self {
+        self.request = Some(AlbumRequest::Tracks { id: albumid.to_string(), market: market.map(str::to_string), limit, offset, max_pages: 1 });
+        self
+    }
+
+    /// Gets a page of the albums newly released on spotify, add [`all_pages`](AlbumData::all_pages) to get the rest of the pages as well
+    pub fn get_new_releases(&mut self, limit: Option<i32>, offset: Option<i32>) -> &mut Self {
+        self.request = Some(AlbumRequest::NewReleases { limit, offset, max_pages: 1 });
+        self
+    }
+
+    /// Gets up to `max_pages` pages of the album's tracks or the new releases, see [paging](crate::core::paginator)
+    pub fn all_pages(&mut self, max_pages: usize) -> Result<&mut Self, Error> {
+        paginator::all_pages(self.request.as_mut(), max_pages)?;
+        Ok(self)
+    }
+
+    /// A stream of every track on the album, pages are only fetched when the tracks in the last one have been used up.
+    pub fn track_stream(&self, albumid: &str, market: Option<&str>) -> impl Stream<Item = Result<SimplifiedTrack, Error>> {
+        let query = query(&[("market", market.map(str::to_string)), ("limit", Some("50".to_string()))]);
+        paginate::<AlbumTracks>(self.http.clone(), self.http.url(&format!("/albums/{}/tracks", albumid)), query)
+    }
+
+    /// A stream of every new release
+    pub fn new_release_stream(&self) -> impl Stream<Item = Result<Album, Error>> {
+        paginate::<NewReleases>(self.http.clone(), self.http.url("/browse/new-releases"), vec![("limit", "50".to_string())])
+    }
+
+    pub async fn run(&mut self) -> Result<AlbumResult, Error> {
+        let request = take_request(&mut self.request)?;
+
+        match request {
+            AlbumRequest::Album { id, market } => {
+                let key = format!("{}{}", id, market.as_deref().unwrap_or_default());
+                if let Some(value) = self.cache.get(&key) {
+                    return Ok(value);
+                }
+                let url = self.http.url(&format!("/albums/{}", id));
+                let album: FullAlbum = self.http.get_json(&url, &query(&[("market", market)])).await?;
+                let value = AlbumResult::Album(Box::new(album));
+                self.cache.add(&key, value.clone());
+                Ok(value)
+            }
+            AlbumRequest::Albums { ids, market } => {
+                let albums = get_several(&self.http, "/albums", "albums", &ids, ALBUM_CHUNK, &query(&[("market", market)])).await?;
+                Ok(AlbumResult::Albums(albums))
+            }
+            AlbumRequest::Tracks { id, market, limit, offset, max_pages } => {
+                let url = self.http.url(&format!("/albums/{}/tracks", id));
+                let query = query(&[("market", market), ("limit", limit.map(|limit| limit.to_string())), ("offset", offset.map(|offset| offset.to_string()))]);
+                let tracks: AlbumTracks = self.http.get_json(&url, &query).await?;
+                Ok(AlbumResult::Tracks(fetch_pages(&self.http, tracks, max_pages).await?))
+            }
+            AlbumRequest::NewReleases { limit, offset, max_pages } => {
+                let url = self.http.url("/browse/new-releases");
+                let query = query(&[("limit", limit.map(|limit| limit.to_string())), ("offset", offset.map(|offset| offset.to_string()))]);
+                let releases: NewReleases = self.http.get_json(&url, &query).await?;
+                Ok(AlbumResult::NewReleases(fetch_pages(&self.http, releases, max_pages).await?.albums))
+            }
+        }
+    }
+}
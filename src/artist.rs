@@ -1,347 +1,360 @@
-use crate::core::{auth::get_token, cache::Cache, structs::artist_structs::{Albums, Artist, Artists, TopTracks}};
+use crate::core::{batch::get_several, cache::Cache, endpoint::{take_request, GenerateConstructors}, http::{query, Http}, paginator::{self, fetch_pages, paginate, PagedRequest}, structs::artist_structs::{Albums, Artist, Artists, Followers, Image, Item, TopTracks}};
+use crate::error::Error;
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
-use reqwest::{Client, Response};
+use futures::stream::{self, Stream, StreamExt};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+// Get Several Artists only takes 50 ids at a time
+const ARTIST_CHUNK: usize = 50;
+
+#[derive(Debug, Clone)]
 pub struct ArtistData {
     pub userid: String,
     cache: Cache<ArtistResult>,
-    token: String,
-    body: HashMap<String, BodyValues>,
-    pub artistid: String,
-    pub artist: bool,
-    pub artists: bool,
-    pub albums: bool,
-    pub top_tracks: bool,
-    pub related_artists: bool,
+    http: Http,
+    request: Option<ArtistRequest>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum ArtistResult {
     Artist(Artist),
-    Artists(Artists),
+    /// In the same order as the ids that were asked for, None for ids spotify doesn't know.
+    /// The list accessors like [`names`](ArtistResult::names) keep the same order, with None in the same places.
+    Artists(Vec<Option<Artist>>),
     Albums(Albums),
     TopTracks(TopTracks),
     RelatedArtists(Artists),
     Null,
-    Error(ArtistErrors),
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub enum ArtistErrors {
-    ArtistNotFound,
-    InvalidArguments,
-    ReqwestError,
+/// One request to the artist endpoints, holding everything needed to send it.
+///
+/// The builder functions on [`ArtistData`] make one of these for `run`, they can also be made on their own
+/// and sent with [`ArtistData::execute`], which doesn't change the client so many can be sent at once.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ArtistRequest {
+    /// Answered with [`ArtistResult::Artist`]
+    Artist { id: String },
+    /// Answered with [`ArtistResult::Artists`], any number of ids can be given
+    Artists { ids: Vec<String> },
+    /// Answered with [`ArtistResult::Albums`], up to `max_pages` pages put together into one
+    Albums { id: String, groups: Option<Vec<String>>, market: Option<String>, limit: Option<i32>, offset: Option<i32>, max_pages: usize },
+    /// Answered with [`ArtistResult::TopTracks`]
+    TopTracks { id: String, market: String },
+    /// Answered with [`ArtistResult::RelatedArtists`]
+    RelatedArtists { id: String },
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub enum BodyValues {
-    Str(String),
-    Int(i32),
+impl ArtistRequest {
+    pub fn artist(artistid: &str) -> ArtistRequest {
+        ArtistRequest::Artist { id: artistid.to_string() }
+    }
+
+    pub fn artists(ids: &[&str]) -> ArtistRequest {
+        ArtistRequest::Artists { ids: ids.iter().map(|id| id.to_string()).collect() }
+    }
+
+    pub fn albums(artistid: &str, groups: Option<Vec<&str>>, market: Option<&str>, limit: Option<i32>, offset: Option<i32>) -> ArtistRequest {
+        ArtistRequest::Albums {
+            id: artistid.to_string(),
+            groups: groups.map(|groups| groups.iter().map(|group| group.to_string()).collect()),
+            market: market.map(str::to_string),
+            limit,
+            offset,
+            max_pages: 1,
+        }
+    }
+
+    /// Gets up to `max_pages` pages of albums, see [paging](crate::core::paginator)
+    pub fn all_pages(mut self, max_pages: usize) -> Result<ArtistRequest, Error> {
+        paginator::all_pages(Some(&mut self), max_pages)?;
+        Ok(self)
+    }
+
+    pub fn top_tracks(artistid: &str, market: &str) -> ArtistRequest {
+        ArtistRequest::TopTracks { id: artistid.to_string(), market: market.to_string() }
+    }
+
+    pub fn related_artists(artistid: &str) -> ArtistRequest {
+        ArtistRequest::RelatedArtists { id: artistid.to_string() }
+    }
+
+    fn path(&self) -> String {
+        match self {
+            ArtistRequest::Artist { id } => format!("/artists/{}", id),
+            ArtistRequest::Artists { .. } => "/artists".to_string(),
+            ArtistRequest::Albums { id, .. } => format!("/artists/{}/albums", id),
+            ArtistRequest::TopTracks { id, .. } => format!("/artists/{}/top-tracks", id),
+            ArtistRequest::RelatedArtists { id } => format!("/artists/{}/related-artists", id),
+        }
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        match self {
+            ArtistRequest::Albums { groups, market, limit, offset, .. } => query(&[
+                ("include_groups", groups.as_ref().map(|groups| groups.join(","))),
+                ("market", market.clone()),
+                ("limit", limit.map(|limit| limit.to_string())),
+                ("offset", offset.map(|offset| offset.to_string())),
+            ]),
+            ArtistRequest::TopTracks { market, .. } => vec![("market", market.clone())],
+            ArtistRequest::Artist { .. } | ArtistRequest::Artists { .. } | ArtistRequest::RelatedArtists { .. } => Vec::new(),
+        }
+    }
 }
 
+impl PagedRequest for ArtistRequest {
+    fn max_pages(&mut self) -> Option<&mut usize> {
+        match self {
+            ArtistRequest::Albums { max_pages, .. } => Some(max_pages),
+            ArtistRequest::Artist { .. } | ArtistRequest::Artists { .. } | ArtistRequest::TopTracks { .. } | ArtistRequest::RelatedArtists { .. } => None,
+        }
+    }
+}
+
+// Accessors for a single artist, these return None for every other kind of result
 macro_rules! GenerateFunction {
-    ($doc: expr, $name: ident, $datatype:ty, $($field:tt).+, unwrap) => {
+    ($doc: expr, $name: ident, $datatype:ty, $($field:tt).+, optional) => {
         #[doc=$doc]
-        pub fn $name(&self) -> $datatype {
-            if let ArtistResult::Artist(artist_data) = self {
-                artist_data.$($field).+.clone().unwrap()
-            }
-            else {
-                panic!("Could not get data from: {:?}", self)
+        pub fn $name(&self) -> Option<$datatype> {
+            match self {
+                ArtistResult::Artist(artist_data) => artist_data.$($field).+.clone(),
+                _ => None,
             }
         }
     };
 
     ($doc: expr, $name: ident, $datatype:ty, $($field:tt).+) => {
         #[doc=$doc]
-        pub fn $name(&self) -> $datatype {
-            if let ArtistResult::Artist(artist_data) = self {
-                artist_data.$($field).+.clone()
-            }
-            else {
-                panic!("Could not get data from: {:?}", self)
+        pub fn $name(&self) -> Option<$datatype> {
+            match self {
+                ArtistResult::Artist(artist_data) => Some(artist_data.$($field).+.clone()),
+                _ => None,
             }
         }
     };
 }
 
+// Accessors for results holding a list, each `Variant.list => |item| value` says where the list is in that kind of result
+// (just `Variant` if the result is the list) and how to get the value out of one item.
+// There is one entry for every item so they line up with the list (and with the ids that were asked for), None where the item
+// doesn't have the value or spotify didn't know the id. None is returned for any other kind of result
 macro_rules! GenerateFunctions {
-    ($doc: expr, $name: ident, $datatype:ty, $($field:tt).+) => {
+    ($doc: expr, $name: ident, $datatype:ty, $($variant:ident $(. $list:ident)? => |$item:ident| $value:expr),+) => {
         #[doc=$doc]
-        pub fn $name(&self) -> Vec<$datatype> {
-            if let ArtistResult::Artists(artist_data) = self {
-                let mut vec = Vec::new();
-                for artist in &artist_data.artists {
-                    vec.push(artist.$($field).+.clone());
-                }
-                vec
-            }
-            else if let ArtistResult::RelatedArtists(artist_data) = self {
-                let mut vec = Vec::new();
-                for artist in &artist_data.artists {
-                    vec.push(artist.$($field).+.clone());
-                }
-                vec
-            }
-            else {
-                panic!("Could not get data from: {:?}", self)
+        pub fn $name(&self) -> Option<Vec<Option<$datatype>>> {
+            match self {
+                $(ArtistResult::$variant(data) => Some(data$(.$list)?.iter().map(|$item| $value).collect()),)+
+                _ => None,
             }
-        } 
+        }
     };
 }
 
-impl Albums {
-    // Concatenate two Albums instances
-    pub(crate) fn concatenate(mut self, mut other: Albums) -> Self {
-        self.items.append(&mut other.items);
-        self.total += other.total;
-        self.next = other.next.or(self.next); // Take the next from other if present
-        self
-    }
-}
-
 impl ArtistData {
-    pub fn new(uid: String, secret: String) -> ArtistData {
+    GenerateConstructors!(app ArtistData, Cache);
+
+    pub(crate) fn from_http(http: Http, cache: Cache<ArtistResult>) -> ArtistData {
         ArtistData {
-            userid: uid.to_owned(),
-            cache: Cache::new(),
-            token: get_token(uid, secret),
-            body: HashMap::new(),
-            artistid: String::new(),
-            artist: false,
-            artists: false,
-            albums: false,
-            top_tracks: false,
-            related_artists: false,
+            userid: http.tokens().client_id().to_owned(),
+            cache,
+            http,
+            request: None,
         }
     }
 
     pub fn get_artist(&mut self, artistid: &str) -> &mut Self {
-        self.artist = true;
-        self.artistid = "/".to_string() + artistid;
+        self.request = Some(ArtistRequest::artist(artistid));
         self
     }   
 
+    /// Gets any number of artists, 50 at a time, artists already in the cache aren't asked for again
     pub fn get_artists(&mut self, ids: &[&str]) -> &mut Self {
-        // Don't take from the cache due to the nature of the function but do add, just in case
-        self.artists = true;
-
-        let mut string = "?ids=".to_string();
-        for i in 0..ids.len() {
-            if i < ids.len()-1 {
-                string = string + &format!("{}%2C", ids[i]);
-            }
-            else {
-                string = string + ids[i];
-            }
-        }
-        self.artistid = string;
+        self.request = Some(ArtistRequest::artists(ids));
         self
     }
 
     // make it optional to add the values at all, don't think its a thing in rust but maybe there is a way
-    // fix and follow link example: https://api.spotify.com/v1/artists/0C0XlULifJtAgn6ZNCW2eu/albums?include_groups=album,single,compilation,appears_on&offset=20&limit=20
+    /// Gets a page of the artist's albums, add [`all_pages`](ArtistData::all_pages) to get the rest of the pages as well
     pub fn get_albums(&mut self, artistid: &str, groups: Option<Vec<&str>>, market: Option<&str>, limit: Option<i32>, offset: Option<i32>) -> &mut Self {
-        self.albums = true;
-        self.artistid = "/".to_string() + artistid + "/albums";
+        self.request = Some(ArtistRequest::albums(artistid, groups, market, limit, offset));
+        self
+    }
 
-        if groups.is_some() {
-            self.body.insert("include_groups".to_string(), BodyValues::Str(groups.unwrap().join(",")));
-        }
-        if market.is_some() {
-            self.body.insert("market".to_string(), BodyValues::Str(market.unwrap().to_string()));
-        }
-        if limit.is_some() {
-            self.body.insert("limit".to_string(), BodyValues::Int(limit.unwrap()));
+    /// Gets up to `max_pages` pages of the artist's albums, see [paging](crate::core::paginator)
+    pub fn all_pages(&mut self, max_pages: usize) -> Result<&mut Self, Error> {
+        paginator::all_pages(self.request.as_mut(), max_pages)?;
+        Ok(self)
+    }
+
+    /// A stream of every one of the artist's albums, pages are only fetched when the albums in the last one have been used up.
+    ///
+    /// This is better than [`all_pages`](ArtistData::all_pages) for artists with very big discographies.
+    pub fn album_stream(&self, artistid: &str, groups: Option<Vec<&str>>, market: Option<&str>) -> impl Stream<Item = Result<Item, Error>> {
+        let mut query = vec![("limit", "50".to_string())];
+        if let Some(groups) = groups {
+            query.push(("include_groups", groups.join(",")));
         }
-        if offset.is_some() {
-            self.body.insert("offset".to_string(), BodyValues::Int(offset.unwrap()));
+        if let Some(market) = market {
+            query.push(("market", market.to_string()));
         }
-        self
+        paginate::<Albums>(self.http.clone(), self.http.url(&format!("/artists/{}/albums", artistid)), query)
     }
 
     // WHY DOES THIS ONE NEED MARKET BUT THE OTHER ONE IS ONLY OPTIONAL?!
     pub fn get_top_tracks(&mut self, artistid: &str, market: &str) -> &mut Self {
-        self.top_tracks = true;
-        self.artistid = "/".to_string() + artistid + "/top-tracks";
-
-        self.body.insert("market".to_string(), BodyValues::Str(market.to_string()));
+        self.request = Some(ArtistRequest::top_tracks(artistid, market));
         self
     }
 
     pub fn get_related_artists(&mut self, artistid: &str) -> &mut Self {
-        self.related_artists = true;
-        self.artistid = "/".to_string() + artistid + "/related-artists";
+        self.request = Some(ArtistRequest::related_artists(artistid));
         self
     }
 
-    #[tokio::main]
-    pub async fn run(&mut self) -> ArtistResult {
-        if self.cache.contains_key(&self.artistid) {
-            let value = self.cache.get(&self.artistid);
-            return value.unwrap().to_owned();
+    pub async fn run(&mut self) -> Result<ArtistResult, Error> {
+        let request = take_request(&mut self.request)?;
+        self.execute(request).await
+    }
+
+    /// Sends a request made on its own, getting as many pages of albums as [`ArtistRequest::all_pages`] asked for.
+    ///
+    /// Nothing set up with the builder functions is used or changed so this can be called from many tasks at once.
+    pub async fn execute(&self, request: ArtistRequest) -> Result<ArtistResult, Error> {
+        // Single artists are read from the cache here, several artists are looked up one at a time in `several`
+        let key = request.path();
+        if let ArtistRequest::Artist { .. } = request {
+            if let Some(value) = self.cache.get(&key) {
+                return Ok(value);
+            }
         }
 
-        let client = Client::new();
-        let url: String = format!("https://api.spotify.com/v1/artists{}", self.artistid);
-        let mut auth_string = format!("Bearer {}", self.token);
-        auth_string = auth_string.replace('\\', "");
-        auth_string = auth_string.replace('\"', "");
-
-        let mut send = client.get(url).header("Authorization", auth_string);
-        
-        if !self.body.is_empty() {
-            for (key, value) in self.body.iter() {
-                match value {
-                    BodyValues::Str(s) => {
-                        send = send.query(&[(key.as_str(), s.as_str())]);
-                    }
-                    BodyValues::Int(i) => {
-                        send = send.query(&[(key.as_str(), *i)]);
-                    }
-                }
+        let url = self.http.url(&key);
+        let query = request.query();
+        match request {
+            ArtistRequest::Artist { .. } => {
+                let value = ArtistResult::Artist(self.http.get_json(&url, &query).await?);
+                self.cache.add(&key, value.clone());
+                Ok(value)
+            }
+            ArtistRequest::Artists { ids } => Ok(ArtistResult::Artists(self.several(&ids).await?)),
+            ArtistRequest::Albums { max_pages, .. } => {
+                let albums: Albums = self.http.get_json(&url, &query).await?;
+                Ok(ArtistResult::Albums(fetch_pages(&self.http, albums, max_pages).await?))
             }
+            ArtistRequest::TopTracks { .. } => Ok(ArtistResult::TopTracks(self.http.get_json(&url, &query).await?)),
+            ArtistRequest::RelatedArtists { .. } => Ok(ArtistResult::RelatedArtists(self.http.get_json(&url, &query).await?)),
         }
+    }
 
-        let response = match send.send().await{
-            Ok(response) => response,
-            Err(_err) => return ArtistResult::Error(ArtistErrors::ReqwestError),
-        };
-        let value = self.parse(response).await;
-        value
+    /// Sends every request with up to `concurrency` of them waiting on spotify at once, the results are in the same order as `requests`.
+    ///
+    /// Each request gets its own result so one failing doesn't lose the others, they all share this client's cache and rate limiter.
+    pub async fn execute_all<I: IntoIterator<Item = ArtistRequest>>(&self, requests: I, concurrency: usize) -> Vec<Result<ArtistResult, Error>> {
+        stream::iter(requests)
+            .map(|request| self.execute(request))
+            .buffered(concurrency.max(1))
+            .collect()
+            .await
     }
 
-    async fn parse(&mut self, response: Response) -> ArtistResult {
-        if response.status() == reqwest::StatusCode::NOT_FOUND{
-            return ArtistResult::Error(ArtistErrors::ArtistNotFound);
-        }
-        else if self.artist {
-            self.artist = false;
-            let response = match response.json::<Artist>().await{
-                Ok(response) => response,
-                Err(err) =>  {println!("{:?}", err); return ArtistResult::Error(ArtistErrors::ReqwestError)},
-            };
-            let value = ArtistResult::Artist(response);
-            self.cache.add(&self.artistid, value.clone());
-            value
-        }
-        else if self.artists {
-            self.artists = false;
-            let response = match response.json::<Artists>().await{
-                Ok(response) => response,
-                Err(err) => {println!("{:?}", err); return ArtistResult::Error(ArtistErrors::ReqwestError)},
-            };
-            let value = ArtistResult::Artists(response.clone());
-            let ids: Vec<&str> = self.artistid.split(',').collect();
-            for i in 0..ids.len() {
-                self.cache.add(ids[i], ArtistResult::Artist(response.artists[i].clone()))
+    // Takes what it can from the cache and asks spotify for the rest, then puts them back in the order of `ids`
+    async fn several(&self, ids: &[String]) -> Result<Vec<Option<Artist>>, Error> {
+        let mut found: HashMap<&str, Option<Artist>> = HashMap::new();
+        let mut missing: Vec<String> = Vec::new();
+        let mut seen = HashSet::new();
+        for id in ids {
+            if !seen.insert(id.as_str()) {
+                continue;
             }
-            value    
-        }
-        else if self.albums {
-            self.albums = false;
-            let response = match response.json::<Albums>().await{
-                Ok(response) => response,
-                Err(err) => {println!("{:?}", err); return ArtistResult::Error(ArtistErrors::ReqwestError)},
-            };
-            let mut value = ArtistResult::Albums(response.clone());
-            if response.clone().next.is_some() {
-                let url = response.clone().next.unwrap();
-                value = self.next(&url, response.clone()).await;
+            match self.cache.get(&ArtistRequest::artist(id).path()) {
+                Some(ArtistResult::Artist(artist)) => {
+                    found.insert(id, Some(artist));
+                }
+                _ => missing.push(id.clone()),
             }
-            value
-        } 
-        else if self.top_tracks {
-            self.top_tracks = false;
-            let response = match response.json::<TopTracks>().await{
-                Ok(response) => response,
-                Err(err) => {println!("{:?}", err); return ArtistResult::Error(ArtistErrors::ReqwestError)},
-            };
-            let value = ArtistResult::TopTracks(response.clone());
-            value  
-        } 
-        else if self.related_artists {
-            self.related_artists = false;
-            let response = match response.json::<Artists>().await{
-                Ok(response) => response,
-                Err(err) => {println!("{:?}", err); return ArtistResult::Error(ArtistErrors::ReqwestError)},
-            };
-            let value = ArtistResult::RelatedArtists(response.clone());
-            value 
         }
-        else {
-            ArtistResult::Error(ArtistErrors::ReqwestError)
-        }
-    }
 
-    async fn next(&self, url: &str, value: Albums) -> ArtistResult {
-        let client = Client::new();
-
-        let mut auth_string = format!("Bearer {}", self.token);
-        auth_string = auth_string.replace('\\', "");
-        auth_string = auth_string.replace('\"', "");
-
-        let send = client.get(url).header("Authorization", auth_string).send().await.unwrap();
-        
-        let mut response = match send.json::<Albums>().await{
-            Ok(response) => response,
-            Err(err) => {println!("{:?}", err); return ArtistResult::Error(ArtistErrors::ReqwestError)},
-        };
-
-        response = value.concatenate(response.clone());
-        let value2 = ArtistResult::Albums(response.clone());
-        // ? if there is ever that many, idk value2 needs to be mut then 
-        //and #[async_recursion] needs to be added over with the async recursion crate
-        //use async_recursion::async_recursion;
-        //async-recursion = "1.0.5"
-        //if response.clone().next.is_some() {
-        //    let url = response.clone().next.unwrap();
-        //    value2 = self.next(&url, response.clone()).await;
-        //}
-        value2
+        let artists: Vec<Option<Artist>> = get_several(&self.http, "/artists", "artists", &missing, ARTIST_CHUNK, &[]).await?;
+        for (id, artist) in missing.iter().zip(artists) {
+            // Unknown ids aren't cached so they are asked about again next time
+            if let Some(artist) = &artist {
+                self.cache.add(&ArtistRequest::artist(id).path(), ArtistResult::Artist(artist.clone()));
+            }
+            found.insert(id, artist);
+        }
+        Ok(ids.iter().map(|id| found.get(id.as_str()).cloned().flatten()).collect())
     }
 }
 
 impl ArtistResult {
     
-    GenerateFunction!("Gets the name of the artist from the ArtistResult struct and returns it as a String", 
+    GenerateFunction!("Gets the name of the artist from the ArtistResult struct, None if it is not a single artist", 
     name, String, name);
     
-    GenerateFunctions!("Gets the names of the artist from the ArtistResult struct and returns it as a Vec<String>",
-    names, String, name);
-
-    GenerateFunction!("Gets the id of the artist from the ArtistResult struct and returns it as a string", 
+    GenerateFunctions!("Gets the names of the artists, albums or tracks in the ArtistResult struct, one for each of them",
+    names, String,
+    Artists => |artist| artist.as_ref().map(|artist| artist.name.clone()),
+    RelatedArtists.artists => |artist| Some(artist.name.clone()),
+    Albums.items => |album| Some(album.name.clone()),
+    TopTracks.tracks => |track| Some(track.name.clone()));
+
+    GenerateFunction!("Gets the id of the artist from the ArtistResult struct, None if it is not a single artist", 
     id, String, id);
     
-    GenerateFunctions!("Gets the ids of the artist from the ArtistResult struct and returns it as a Vec<String>",
-    ids, String, id);
-
-    GenerateFunction!("Gets the popularity of the artist from the ArtistResult struct and returns it as a i32.
+    GenerateFunctions!("Gets the ids of the artists, albums or tracks in the ArtistResult struct, None for ids spotify didn't know",
+    ids, String,
+    Artists => |artist| artist.as_ref().map(|artist| artist.id.clone()),
+    RelatedArtists.artists => |artist| Some(artist.id.clone()),
+    Albums.items => |album| Some(album.id.clone()),
+    TopTracks.tracks => |track| Some(track.id.clone()));
+
+    GenerateFunction!("Gets the popularity of the artist from the ArtistResult struct and returns it as an `i32`.
     More info on popularity and how it works [here](https://developer.spotify.com/documentation/web-api/reference/get-an-artists-top-tracks)", 
-    popularity, i32, popularity, unwrap);
+    popularity, i32, popularity, optional);
     
-    pub fn popularitys(&self) -> Vec<i32> {
-        if let ArtistResult::Artists(artist_data) = self {
-            let mut vec = Vec::new();
-            for artist in &artist_data.artists {
-                vec.push(artist.popularity.clone().unwrap());
-            }
-            vec
-        }
-        else if let ArtistResult::RelatedArtists(artist_data) = self {
-            let mut vec = Vec::new();
-            for artist in &artist_data.artists{
-                vec.push(artist.popularity.clone().unwrap());
-            }
-            vec
-        }
-        else {
-            panic!("Could not get the artists popularity's")
-        }
-    }
+    GenerateFunctions!("Gets the popularity of the artists or tracks in the ArtistResult struct, None for any without one",
+    popularitys, i32,
+    Artists => |artist| artist.as_ref().and_then(|artist| artist.popularity),
+    RelatedArtists.artists => |artist| artist.popularity,
+    TopTracks.tracks => |track| Some(track.popularity));
+
+    GenerateFunction!("Gets the genres of the artist from the ArtistResult struct and returns them as a `Vec<String>`", 
+    genres, Vec<String>, genres, optional);
+
+    GenerateFunctions!("Gets the genres of each of the artists in the ArtistResult struct",
+    all_genres, Vec<String>,
+    Artists => |artist| artist.as_ref().and_then(|artist| artist.genres.clone()),
+    RelatedArtists.artists => |artist| artist.genres.clone());
+
+    GenerateFunction!("Gets the followers of the artist from the ArtistResult struct", 
+    followers, Followers, followers, optional);
+
+    GenerateFunctions!("Gets the followers of each of the artists in the ArtistResult struct",
+    all_followers, Followers,
+    Artists => |artist| artist.as_ref().and_then(|artist| artist.followers.clone()),
+    RelatedArtists.artists => |artist| artist.followers.clone());
+
+    GenerateFunction!("Gets the images of the artist from the ArtistResult struct", 
+    images, Vec<Image>, images, optional);
+
+    GenerateFunctions!("Gets the images of each of the artists or albums (the album cover for tracks) in the ArtistResult struct",
+    all_images, Vec<Image>,
+    Artists => |artist| artist.as_ref().and_then(|artist| artist.images.clone()),
+    RelatedArtists.artists => |artist| artist.images.clone(),
+    Albums.items => |album| Some(album.images.clone()),
+    TopTracks.tracks => |track| Some(track.album.images.clone()));
+
+    GenerateFunction!("Gets the link to open the artist in spotify from the ArtistResult struct", 
+    external_url, String, external_urls.spotify);
+
+    GenerateFunctions!("Gets the links to open each of the artists, albums or tracks in spotify from the ArtistResult struct",
+    external_urls, String,
+    Artists => |artist| artist.as_ref().map(|artist| artist.external_urls.spotify.clone()),
+    RelatedArtists.artists => |artist| Some(artist.external_urls.spotify.clone()),
+    Albums.items => |album| Some(album.external_urls.spotify.clone()),
+    TopTracks.tracks => |track| Some(track.external_urls.spotify.clone()));
 
-}
\ No newline at end of file
+}
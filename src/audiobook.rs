@@ -0,0 +1,114 @@
+use crate::core::{batch::get_several, endpoint::{take_request, GenerateConstructors}, http::{query, Http}, paginator::{self, fetch_pages, paginate, PagedRequest}};
+use crate::core::structs::audiobook_structs::{Audiobook, AudiobookChapters, Chapter, FullAudiobook};
+use crate::error::Error;
+
+use futures::stream::Stream;
+use serde::{Deserialize, Serialize};
+
+// Get Several Audiobooks only takes 50 ids at a time
+const AUDIOBOOK_CHUNK: usize = 50;
+
+/// Gets audiobooks.
+///
+/// Like chapters on their own (see [`ChapterData`](crate::chapter::ChapterData)), audiobooks aren't cached because the first page
+/// of chapters that comes with one has the `resume_point` of the user, if it was fetched with a user token.
+#[derive(Debug, Clone)]
+pub struct AudiobookData {
+    pub userid: String,
+    http: Http,
+    request: Option<AudiobookRequest>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum AudiobookResult {
+    Audiobook(Box<FullAudiobook>),
+    /// In the same order as the ids that were asked for, None for ids spotify doesn't know or that aren't available in the market
+    Audiobooks(Vec<Option<Audiobook>>),
+    Chapters(AudiobookChapters),
+}
+
+// What the next call to run will fetch
+#[derive(Debug, Clone, PartialEq)]
+enum AudiobookRequest {
+    Audiobook { id: String, market: Option<String> },
+    Audiobooks { ids: Vec<String>, market: Option<String> },
+    Chapters { id: String, market: Option<String>, limit: Option<i32>, offset: Option<i32>, max_pages: usize },
+}
+
+impl PagedRequest for AudiobookRequest {
+    fn max_pages(&mut self) -> Option<&mut usize> {
+        match self {
+            AudiobookRequest::Chapters { max_pages, .. } => Some(max_pages),
+            AudiobookRequest::Audiobook { .. } | AudiobookRequest::Audiobooks { .. } => None,
+        }
+    }
+}
+
+impl AudiobookData {
+    GenerateConstructors!(app AudiobookData);
+
+    pub(crate) fn from_http(http: Http) -> AudiobookData {
+        AudiobookData {
+            userid: http.tokens().client_id().to_owned(),
+            http,
+            request: None,
+        }
+    }
+
+    /// Gets an audiobook with the first page of its chapters.
+    ///
+    /// Spotify only has audiobooks in some markets, with an app token and no market it usually doesn't find the audiobook.
+    pub fn get_audiobook(&mut self, audiobookid: &str, market: Option<&str>) -> &mut Self {
+        self.request = Some(AudiobookRequest::Audiobook { id: audiobookid.to_string(), market: market.map(str::to_string) });
+        self
+    }
+
+    /// Gets any number of audiobooks, they are asked for 50 at a time (all at once) and put back in the order of `ids`
+    pub fn get_audiobooks(&mut self, ids: &[&str], market: Option<&str>) -> &mut Self {
+        self.request = Some(AudiobookRequest::Audiobooks {
+            ids: ids.iter().map(|id| id.to_string()).collect(),
+            market: market.map(str::to_string),
+        });
+        self
+    }
+
+    /// Gets a page of the audiobook's chapters, add [`all_pages`](AudiobookData::all_pages) to get the rest of the pages as well
+    pub fn get_chapters(&mut self, audiobookid: &str, market: Option<&str>, limit: Option<i32>, offset: Option<i32>) -> &mut Self {
+        self.request = Some(AudiobookRequest::Chapters { id: audiobookid.to_string(), market: market.map(str::to_string), limit, offset, max_pages: 1 });
+        self
+    }
+
+    /// Gets up to `max_pages` pages of the audiobook's chapters, see [paging](crate::core::paginator)
+    pub fn all_pages(&mut self, max_pages: usize) -> Result<&mut Self, Error> {
+        paginator::all_pages(self.request.as_mut(), max_pages)?;
+        Ok(self)
+    }
+
+    /// A stream of every chapter of the audiobook, pages are only fetched when the chapters in the last one have been used up.
+    pub fn chapter_stream(&self, audiobookid: &str, market: Option<&str>) -> impl Stream<Item = Result<Chapter, Error>> {
+        let query = query(&[("market", market.map(str::to_string)), ("limit", Some("50".to_string()))]);
+        paginate::<AudiobookChapters>(self.http.clone(), self.http.url(&format!("/audiobooks/{}/chapters", audiobookid)), query)
+    }
+
+    pub async fn run(&mut self) -> Result<AudiobookResult, Error> {
+        let request = take_request(&mut self.request)?;
+
+        match request {
+            AudiobookRequest::Audiobook { id, market } => {
+                let url = self.http.url(&format!("/audiobooks/{}", id));
+                let audiobook: FullAudiobook = self.http.get_json(&url, &query(&[("market", market)])).await?;
+                Ok(AudiobookResult::Audiobook(Box::new(audiobook)))
+            }
+            AudiobookRequest::Audiobooks { ids, market } => {
+                let audiobooks = get_several(&self.http, "/audiobooks", "audiobooks", &ids, AUDIOBOOK_CHUNK, &query(&[("market", market)])).await?;
+                Ok(AudiobookResult::Audiobooks(audiobooks))
+            }
+            AudiobookRequest::Chapters { id, market, limit, offset, max_pages } => {
+                let url = self.http.url(&format!("/audiobooks/{}/chapters", id));
+                let query = query(&[("market", market), ("limit", limit.map(|limit| limit.to_string())), ("offset", offset.map(|offset| offset.to_string()))]);
+                let chapters: AudiobookChapters = self.http.get_json(&url, &query).await?;
+                Ok(AudiobookResult::Chapters(fetch_pages(&self.http, chapters, max_pages).await?))
+            }
+        }
+    }
+}
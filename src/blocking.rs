@@ -0,0 +1,114 @@
+//! Blocking versions of the rustify clients.
+//!
+//! Everything in rustify is async, this module wraps it up so it can be used from normal synchronous code
+//! (scripts, tests, etc). It is only available with the `blocking` feature.
+//!
+//! Like every blocking wrapper these functions will panic if they are called from inside of an async runtime,
+//! use the async versions there instead.
+
+use crate::artist::{self, ArtistRequest, ArtistResult};
+use crate::core::{auth::{self, Token, TokenProvider}, ratelimits::RateLimiter};
+use crate::error::Error;
+
+use std::future::Future;
+use std::sync::OnceLock;
+
+use tokio::runtime::{Builder, Runtime};
+
+// One runtime for the whole process instead of making a new one for every call
+fn runtime() -> &'static Runtime {
+    static RUNTIME: OnceLock<Runtime> = OnceLock::new();
+    RUNTIME.get_or_init(|| {
+        Builder::new_multi_thread()
+            .worker_threads(1)
+            .enable_all()
+            .build()
+            .expect("Could not start the blocking runtime")
+    })
+}
+
+/// Runs a future to completion on the rustify blocking runtime and returns its output.
+///
+/// This can be used to call any of the async functions in rustify from synchronous code.
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    runtime().block_on(future)
+}
+
+/// Blocking version of [`auth::get_token`]
+pub fn get_token(id: String, secret: String) -> Result<Token, Error> {
+    block_on(auth::get_token(id, secret))
+}
+
+/// Blocking version of [`artist::ArtistData`], the builder functions are the same but `run` waits for the result
+#[derive(Debug, Clone)]
+pub struct ArtistData {
+    inner: artist::ArtistData,
+}
+
+impl ArtistData {
+    pub fn new(uid: String, secret: String) -> Result<ArtistData, Error> {
+        Ok(ArtistData {
+            inner: block_on(artist::ArtistData::new(uid, secret))?,
+        })
+    }
+
+    pub fn with_rate_limiter(uid: String, secret: String, limiter: RateLimiter) -> Result<ArtistData, Error> {
+        Ok(ArtistData {
+            inner: block_on(artist::ArtistData::with_rate_limiter(uid, secret, limiter))?,
+        })
+    }
+
+    pub fn from_provider(tokens: TokenProvider, limiter: RateLimiter) -> ArtistData {
+        ArtistData {
+            inner: artist::ArtistData::from_provider(tokens, limiter),
+        }
+    }
+
+    pub fn api_url(&mut self, url: &str) -> &mut Self {
+        self.inner.api_url(url);
+        self
+    }
+
+    pub fn get_artist(&mut self, artistid: &str) -> &mut Self {
+        self.inner.get_artist(artistid);
+        self
+    }
+
+    pub fn get_artists(&mut self, ids: &[&str]) -> &mut Self {
+        self.inner.get_artists(ids);
+        self
+    }
+
+    pub fn get_albums(&mut self, artistid: &str, groups: Option<Vec<&str>>, market: Option<&str>, limit: Option<i32>, offset: Option<i32>) -> &mut Self {
+        self.inner.get_albums(artistid, groups, market, limit, offset);
+        self
+    }
+
+    pub fn all_pages(&mut self, max_pages: usize) -> Result<&mut Self, Error> {
+        self.inner.all_pages(max_pages)?;
+        Ok(self)
+    }
+
+    pub fn get_top_tracks(&mut self, artistid: &str, market: &str) -> &mut Self {
+        self.inner.get_top_tracks(artistid, market);
+        self
+    }
+
+    pub fn get_related_artists(&mut self, artistid: &str) -> &mut Self {
+        self.inner.get_related_artists(artistid);
+        self
+    }
+
+    pub fn run(&mut self) -> Result<ArtistResult, Error> {
+        block_on(self.inner.run())
+    }
+
+    pub fn execute(&self, request: ArtistRequest) -> Result<ArtistResult, Error> {
+        block_on(self.inner.execute(request))
+    }
+
+    /// The requests are still sent at the same time, this only waits for all of them
+    pub fn execute_all<I: IntoIterator<Item = ArtistRequest>>(&self, requests: I, concurrency: usize) -> Vec<Result<ArtistResult, Error>> {
+        block_on(self.inner.execute_all(requests, concurrency))
+    }
+}
@@ -0,0 +1,178 @@
+use crate::core::{cache::Cache, endpoint::{take_request, GenerateConstructors}, http::{query, Http}, paginator::{self, fetch_pages, paginate, PagedRequest}};
+use crate::core::structs::{browse_structs::{BrowsePlaylists, Categories, Category, GenreSeeds, Markets}, paging::Paging, playlist_structs::SimplifiedPlaylist};
+use crate::error::Error;
+
+use futures::stream::Stream;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone)]
+pub struct BrowseData {
+    pub userid: String,
+    cache: Cache<BrowseResult>,
+    http: Http,
+    request: Option<BrowseRequest>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum BrowseResult {
+    Categories(Paging<Category>),
+    Category(Category),
+    Playlists(BrowsePlaylists),
+    /// ISO 3166-1 alpha-2 codes of the countries spotify is available in
+    Markets(Vec<String>),
+    /// Genres that can be used as seeds for recommendations
+    Genres(Vec<String>),
+}
+
+// What the next call to run will fetch
+#[derive(Debug, Clone, PartialEq)]
+enum BrowseRequest {
+    Categories { country: Option<String>, locale: Option<String>, limit: Option<i32>, offset: Option<i32>, max_pages: usize },
+    Category { id: String, country: Option<String>, locale: Option<String> },
+    CategoryPlaylists { id: String, country: Option<String>, limit: Option<i32>, offset: Option<i32>, max_pages: usize },
+    FeaturedPlaylists { country: Option<String>, locale: Option<String>, timestamp: Option<String>, limit: Option<i32>, offset: Option<i32>, max_pages: usize },
+    Markets,
+    GenreSeeds,
+}
+
+impl PagedRequest for BrowseRequest {
+    fn max_pages(&mut self) -> Option<&mut usize> {
+        match self {
+            BrowseRequest::Categories { max_pages, .. } | BrowseRequest::CategoryPlaylists { max_pages, .. } | BrowseRequest::FeaturedPlaylists { max_pages, .. } => Some(max_pages),
+            BrowseRequest::Category { .. } | BrowseRequest::Markets | BrowseRequest::GenreSeeds => None,
+        }
+    }
+}
+
+impl BrowseData {
+    GenerateConstructors!(app BrowseData, Cache);
+
+    pub(crate) fn from_http(http: Http, cache: Cache<BrowseResult>) -> BrowseData {
+        BrowseData {
+            userid: http.tokens().client_id().to_owned(),
+            cache,
+            http,
+            request: None,
+        }
+    }
+
+    /// Gets a page of the categories used in the spotify app.
+    ///
+    /// `country` is an ISO 3166-1 alpha-2 code and `locale` a language and country like `es_MX`, the names are in that language.
+    pub fn get_categories(&mut self, country: Option<&str>, locale: Option<&str>, limit: Option<i32>, offset: Option<i32>) -> &mut Self {
+        self.request = Some(BrowseRequest::Categories { country: country.map(str::to_string), locale: locale.map(str::to_string), limit, offset, max_pages: 1 });
+        self
+    }
+
+    pub fn get_category(&mut self, categoryid: &str, country: Option<&str>, locale: Option<&str>) -> &mut Self {
+        self.request = Some(BrowseRequest::Category { id: categoryid.to_string(), country: country.map(str::to_string), locale: locale.map(str::to_string) });
+        self
+    }
+
+    /// Gets a page of the playlists tagged with the category
+    pub fn get_category_playlists(&mut self, categoryid: &str, country: Option<&str>, limit: Option<i32>, offset: Option<i32>) -> &mut Self {
+        self.request = Some(BrowseRequest::CategoryPlaylists { id: categoryid.to_string(), country: country.map(str::to_string), limit, offset, max_pages: 1 });
+        self
+    }
+
+    /// Gets a page of the playlists spotify is featuring.
+    ///
+    /// `timestamp` is a local time like `2014-10-23T09:00:00` to get the playlists for that time of day, instead of right now.
+    pub fn get_featured_playlists(&mut self, country: Option<&str>, locale: Option<&str>, timestamp: Option<&str>, limit: Option<i32>, offset: Option<i32>) -> &mut Self {
+        self.request = Some(BrowseRequest::FeaturedPlaylists {
+            country: country.map(str::to_string),
+            locale: locale.map(str::to_string),
+            timestamp: timestamp.map(str::to_string),
+            limit,
+            offset,
+            max_pages: 1,
+        });
+        self
+    }
+
+    /// Gets the markets spotify is available in
+    pub fn get_markets(&mut self) -> &mut Self {
+        self.request = Some(BrowseRequest::Markets);
+        self
+    }
+
+    /// Gets the genres that can be used to seed recommendations
+    pub fn get_genre_seeds(&mut self) -> &mut Self {
+        self.request = Some(BrowseRequest::GenreSeeds);
+        self
+    }
+
+    /// Gets up to `max_pages` pages of categories or playlists, see [paging](crate::core::paginator)
+    pub fn all_pages(&mut self, max_pages: usize) -> Result<&mut Self, Error> {
+        paginator::all_pages(self.request.as_mut(), max_pages)?;
+        Ok(self)
+    }
+
+    /// A stream of every category, pages are only fetched when the categories in the last one have been used up.
+    pub fn category_stream(&self, country: Option<&str>, locale: Option<&str>) -> impl Stream<Item = Result<Category, Error>> {
+        let query = query(&[("country", country.map(str::to_string)), ("locale", locale.map(str::to_string)), ("limit", Some("50".to_string()))]);
+        paginate::<Categories>(self.http.clone(), self.http.url("/browse/categories"), query)
+    }
+
+    /// A stream of every playlist tagged with the category
+    pub fn category_playlist_stream(&self, categoryid: &str, country: Option<&str>) -> impl Stream<Item = Result<SimplifiedPlaylist, Error>> {
+        let query = query(&[("country", country.map(str::to_string)), ("limit", Some("50".to_string()))]);
+        paginate::<BrowsePlaylists>(self.http.clone(), self.http.url(&format!("/browse/categories/{}/playlists", categoryid)), query)
+    }
+
+    pub async fn run(&mut self) -> Result<BrowseResult, Error> {
+        let request = take_request(&mut self.request)?;
+
+        // Only the lists that hardly ever change are cached, the pages of playlists change with the time of day
+        let key = match &request {
+            BrowseRequest::Category { id, country, locale } => Some(format!("category{}{:?}{:?}", id, country, locale)),
+            BrowseRequest::Markets => Some("markets".to_string()),
+            BrowseRequest::GenreSeeds => Some("genres".to_string()),
+            _ => None,
+        };
+        if let Some(value) = key.as_ref().and_then(|key| self.cache.get(key)) {
+            return Ok(value);
+        }
+
+        let value = match request {
+            BrowseRequest::Categories { country, locale, limit, offset, max_pages } => {
+                let query = query(&[("country", country), ("locale", locale), ("limit", limit.map(|limit| limit.to_string())), ("offset", offset.map(|offset| offset.to_string()))]);
+                let categories: Categories = self.http.get_json(&self.http.url("/browse/categories"), &query).await?;
+                BrowseResult::Categories(fetch_pages(&self.http, categories, max_pages).await?.categories)
+            }
+            BrowseRequest::Category { id, country, locale } => {
+                let url = self.http.url(&format!("/browse/categories/{}", id));
+                BrowseResult::Category(self.http.get_json(&url, &query(&[("country", country), ("locale", locale)])).await?)
+            }
+            BrowseRequest::CategoryPlaylists { id, country, limit, offset, max_pages } => {
+                let url = self.http.url(&format!("/browse/categories/{}/playlists", id));
+                let query = query(&[("country", country), ("limit", limit.map(|limit| limit.to_string())), ("offset", offset.map(|offset| offset.to_string()))]);
+                let playlists: BrowsePlaylists = self.http.get_json(&url, &query).await?;
+                BrowseResult::Playlists(fetch_pages(&self.http, playlists, max_pages).await?)
+            }
+            BrowseRequest::FeaturedPlaylists { country, locale, timestamp, limit, offset, max_pages } => {
+                let query = query(&[
+                    ("country", country),
+                    ("locale", locale),
+                    ("timestamp", timestamp),
+                    ("limit", limit.map(|limit| limit.to_string())),
+                    ("offset", offset.map(|offset| offset.to_string())),
+                ]);
+                let playlists: BrowsePlaylists = self.http.get_json(&self.http.url("/browse/featured-playlists"), &query).await?;
+                BrowseResult::Playlists(fetch_pages(&self.http, playlists, max_pages).await?)
+            }
+            BrowseRequest::Markets => {
+                let markets: Markets = self.http.get_json(&self.http.url("/markets"), &[] as &[(&str, &str)]).await?;
+                BrowseResult::Markets(markets.markets)
+            }
+            BrowseRequest::GenreSeeds => {
+                let genres: GenreSeeds = self.http.get_json(&self.http.url("/recommendations/available-genre-seeds"), &[] as &[(&str, &str)]).await?;
+                BrowseResult::Genres(genres.genres)
+            }
+        };
+        if let Some(key) = key {
+            self.cache.add(&key, value.clone());
+        }
+        Ok(value)
+    }
+}
@@ -0,0 +1,75 @@
+use crate::core::{batch::get_several, endpoint::{take_request, GenerateConstructors}, http::{query, Http}};
+use crate::core::structs::audiobook_structs::Chapter;
+use crate::error::Error;
+
+use serde::{Deserialize, Serialize};
+
+// Get Several Chapters only takes 50 ids at a time
+const CHAPTER_CHUNK: usize = 50;
+
+/// Gets audiobook chapters.
+///
+/// Chapters are never cached, with a user token each one says how far into it the user got (`resume_point`) and that moves as they listen.
+#[derive(Debug, Clone)]
+pub struct ChapterData {
+    pub userid: String,
+    http: Http,
+    request: Option<ChapterRequest>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ChapterResult {
+    Chapter(Box<Chapter>),
+    /// In the same order as the ids that were asked for, None for ids spotify doesn't know or that aren't available in the market
+    Chapters(Vec<Option<Chapter>>),
+}
+
+// What the next call to run will fetch
+#[derive(Debug, Clone, PartialEq)]
+enum ChapterRequest {
+    Chapter { id: String, market: Option<String> },
+    Chapters { ids: Vec<String>, market: Option<String> },
+}
+
+impl ChapterData {
+    GenerateConstructors!(app ChapterData);
+
+    pub(crate) fn from_http(http: Http) -> ChapterData {
+        ChapterData {
+            userid: http.tokens().client_id().to_owned(),
+            http,
+            request: None,
+        }
+    }
+
+    /// Gets a chapter along with its audiobook, chapters that aren't available in `market` come back with `restrictions` or `is_playable: false`
+    pub fn get_chapter(&mut self, chapterid: &str, market: Option<&str>) -> &mut Self {
+        self.request = Some(ChapterRequest::Chapter { id: chapterid.to_string(), market: market.map(str::to_string) });
+        self
+    }
+
+    /// Gets any number of chapters, they are asked for 50 at a time (all at once) and put back in the order of `ids`
+    pub fn get_chapters(&mut self, ids: &[&str], market: Option<&str>) -> &mut Self {
+        self.request = Some(ChapterRequest::Chapters {
+            ids: ids.iter().map(|id| id.to_string()).collect(),
+            market: market.map(str::to_string),
+        });
+        self
+    }
+
+    pub async fn run(&mut self) -> Result<ChapterResult, Error> {
+        let request = take_request(&mut self.request)?;
+
+        match request {
+            ChapterRequest::Chapter { id, market } => {
+                let url = self.http.url(&format!("/chapters/{}", id));
+                let chapter: Chapter = self.http.get_json(&url, &query(&[("market", market)])).await?;
+                Ok(ChapterResult::Chapter(Box::new(chapter)))
+            }
+            ChapterRequest::Chapters { ids, market } => {
+                let chapters = get_several(&self.http, "/chapters", "chapters", &ids, CHAPTER_CHUNK, &query(&[("market", market)])).await?;
+                Ok(ChapterResult::Chapters(chapters))
+            }
+        }
+    }
+}
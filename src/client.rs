@@ -0,0 +1,131 @@
+//! One client for the whole web api.
+//!
+//! [`SpotifyClient`] owns the HTTP connection pool, the token provider, the rate limiter and the caches,
+//! and hands out the endpoint clients from them. Handing one out only clones a few handles, so they can be
+//! made whenever they are needed and moved to other tasks while still sharing everything.
+
+use crate::album::{AlbumData, AlbumResult};
+use crate::artist::{ArtistData, ArtistResult};
+use crate::audiobook::AudiobookData;
+use crate::browse::{BrowseData, BrowseResult};
+use crate::chapter::ChapterData;
+use crate::core::{auth::TokenProvider, cache::Cache, endpoint::GenerateConstructors, http::Http, ratelimits::RateLimiter};
+use crate::core::structs::{search_structs::SearchResults, user_struct::User};
+use crate::episode::EpisodeData;
+use crate::follow::FollowData;
+use crate::library::LibraryData;
+use crate::player::PlayerData;
+use crate::playlist::PlaylistData;
+use crate::search::SearchData;
+use crate::show::ShowData;
+use crate::track::{TrackData, TrackResult};
+use crate::user::UserData;
+
+/// The centralized client, make one and get the endpoint clients from it.
+///
+/// Clients handed out before a call to `api_url` keep sending requests to the old url.
+///
+/// ```no_run
+/// # async fn example(id: String, secret: String) -> Result<(), rustify::Error> {
+/// use rustify::SpotifyClient;
+///
+/// let spotify = SpotifyClient::new(id, secret).await?;
+/// let artist = spotify.artists().get_artist("0C0XlULifJtAgn6ZNCW2eu").run().await?;
+/// let album = spotify.albums().get_album("4aawyAB9vmqN3uQ7FjRGTy", None).run().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct SpotifyClient {
+    http: Http,
+    caches: Caches,
+}
+
+// One cache for each endpoint that caches, shared by every client handed out for it
+#[derive(Debug, Clone, Default)]
+struct Caches {
+    artists: Cache<ArtistResult>,
+    albums: Cache<AlbumResult>,
+    tracks: Cache<TrackResult>,
+    search: Cache<SearchResults>,
+    users: Cache<User>,
+    browse: Cache<BrowseResult>,
+}
+
+impl SpotifyClient {
+    GenerateConstructors!(app SpotifyClient);
+
+    fn from_http(http: Http) -> SpotifyClient {
+        SpotifyClient {
+            http,
+            caches: Caches::default(),
+        }
+    }
+
+    pub fn tokens(&self) -> &TokenProvider {
+        self.http.tokens()
+    }
+
+    pub fn rate_limiter(&self) -> &RateLimiter {
+        self.http.limiter()
+    }
+
+    pub fn artists(&self) -> ArtistData {
+        ArtistData::from_http(self.http.clone(), self.caches.artists.clone())
+    }
+
+    pub fn albums(&self) -> AlbumData {
+        AlbumData::from_http(self.http.clone(), self.caches.albums.clone())
+    }
+
+    pub fn tracks(&self) -> TrackData {
+        TrackData::from_http(self.http.clone(), self.caches.tracks.clone())
+    }
+
+    pub fn playlists(&self) -> PlaylistData {
+        PlaylistData::from_http(self.http.clone())
+    }
+
+    pub fn search(&self) -> SearchData {
+        SearchData::from_http(self.http.clone(), self.caches.search.clone())
+    }
+
+    pub fn users(&self) -> UserData {
+        UserData::from_http(self.http.clone(), self.caches.users.clone())
+    }
+
+    /// Needs a user token, see [`LibraryData`]
+    pub fn library(&self) -> LibraryData {
+        LibraryData::from_http(self.http.clone())
+    }
+
+    /// Needs a user token, see [`FollowData`]
+    pub fn follow(&self) -> FollowData {
+        FollowData::from_http(self.http.clone())
+    }
+
+    /// Needs a user token, see [`PlayerData`]
+    pub fn player(&self) -> PlayerData {
+        PlayerData::from_http(self.http.clone())
+    }
+
+    pub fn shows(&self) -> ShowData {
+        ShowData::from_http(self.http.clone())
+    }
+
+    pub fn episodes(&self) -> EpisodeData {
+        EpisodeData::from_http(self.http.clone())
+    }
+
+    pub fn audiobooks(&self) -> AudiobookData {
+        AudiobookData::from_http(self.http.clone())
+    }
+
+    pub fn chapters(&self) -> ChapterData {
+        ChapterData::from_http(self.http.clone())
+    }
+
+    pub fn browse(&self) -> BrowseData {
+        BrowseData::from_http(self.http.clone(), self.caches.browse.clone())
+    }
+}
@@ -0,0 +1,271 @@
+//  Tokens only last an hour so the TokenProvider keeps one around and gets a new one when it is about to run out
+
+pub mod pkce;
+
+mod store;
+
+pub use store::{JsonFileStore, MemoryStore, TokenStore};
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use reqwest::{Client, header};
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+
+use super::ratelimits::RateLimiter;
+use super::http::decode;
+use crate::error::Error;
+
+pub const TOKEN_URL: &str = "https://accounts.spotify.com/api/token";
+
+/// An access token from spotify and everything that came with it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Token {
+    pub access_token: String,
+    /// Always "Bearer" at the moment
+    pub token_type: String,
+    /// Scopes the token was granted, empty for client credentials tokens
+    pub scopes: Vec<String>,
+    /// When spotify will stop accepting the token
+    pub expires_at: SystemTime,
+    /// Used to get a new access token without asking the user again, only given for user tokens
+    pub refresh_token: Option<String>,
+}
+
+impl Token {
+    pub fn is_expired(&self) -> bool {
+        self.expires_within(Duration::ZERO)
+    }
+
+    /// If the token will have expired `margin` from now
+    pub fn expires_within(&self, margin: Duration) -> bool {
+        SystemTime::now() + margin >= self.expires_at
+    }
+}
+
+// What the accounts service actually sends back
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    token_type: String,
+    #[serde(default)]
+    scope: Option<String>,
+    expires_in: u64,
+    #[serde(default)]
+    refresh_token: Option<String>,
+}
+
+impl From<TokenResponse> for Token {
+    fn from(response: TokenResponse) -> Token {
+        Token {
+            access_token: response.access_token,
+            token_type: response.token_type,
+            scopes: response.scope.unwrap_or_default().split_whitespace().map(str::to_string).collect(),
+            expires_at: SystemTime::now() + Duration::from_secs(response.expires_in),
+            refresh_token: response.refresh_token,
+        }
+    }
+}
+
+/// Things that can go wrong while authorizing a user.
+#[derive(Debug)]
+pub enum AuthError {
+    /// The user said no, or spotify sent back an `error` instead of a code
+    Denied(String),
+    /// The `state` in the redirect was not the one that was sent, the redirect did not come from this request
+    StateMismatch,
+    /// The redirect had neither a `code` nor an `error` in it
+    MissingCode,
+    /// There is no saved refresh token, the user has to log in again
+    MissingRefreshToken,
+    /// The accounts service would not give out a token
+    Rejected { status: u16, body: String },
+}
+
+impl fmt::Display for AuthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AuthError::Denied(reason) => write!(f, "authorization was denied: {}", reason),
+            AuthError::StateMismatch => write!(f, "the state returned in the redirect did not match the one sent"),
+            AuthError::MissingCode => write!(f, "the redirect did not contain an authorization code"),
+            AuthError::MissingRefreshToken => write!(f, "there is no refresh token, the user needs to log in again"),
+            AuthError::Rejected { status, body } => write!(f, "the token request was rejected with status {}: {}", status, body),
+        }
+    }
+}
+
+impl std::error::Error for AuthError {}
+
+// Makes a web app token to be used by an APP!
+pub async fn get_token(id: String, secret: String) -> Result<Token, Error> {
+    get_token_with_limiter(id, secret, &RateLimiter::new()).await
+}
+
+// Same as get_token but the request goes through the given rate limiter
+pub async fn get_token_with_limiter(id: String, secret: String, limiter: &RateLimiter) -> Result<Token, Error> {
+    request_client_credentials(&Client::new(), TOKEN_URL, &id, &secret, limiter).await
+}
+
+async fn request_client_credentials(client: &Client, url: &str, id: &str, secret: &str, limiter: &RateLimiter) -> Result<Token, Error> {
+    let mut request_body = HashMap::new();
+    request_body.insert("grant_type", "client_credentials");
+    request_body.insert("client_id", id);
+    request_body.insert("client_secret", secret);
+    request_token(client, url, &request_body, limiter).await
+}
+
+async fn request_refresh(client: &Client, url: &str, id: &str, refresh_token: &str, limiter: &RateLimiter) -> Result<Token, Error> {
+    let mut request_body = HashMap::new();
+    request_body.insert("grant_type", "refresh_token");
+    request_body.insert("refresh_token", refresh_token);
+    request_body.insert("client_id", id);
+    let mut token = request_token(client, url, &request_body, limiter).await?;
+    // Spotify doesn't always rotate the refresh token, when it doesn't the old one keeps working
+    if token.refresh_token.is_none() {
+        token.refresh_token = Some(refresh_token.to_string());
+    }
+    Ok(token)
+}
+
+// Posts a form to the accounts service and reads the token out of the response
+pub(crate) async fn request_token<T: serde::Serialize + ?Sized>(client: &Client, url: &str, form: &T, limiter: &RateLimiter) -> Result<Token, Error> {
+    let request = client
+        .post(url)
+        .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded").form(form);
+    let response = limiter.send(request).await?;
+
+    let status = response.status();
+    let body = response.text().await?;
+    if !status.is_success() {
+        return Err(AuthError::Rejected { status: status.as_u16(), body }.into());
+    }
+    let value: TokenResponse = decode(body.as_bytes())?;
+    Ok(value.into())
+}
+
+// How the provider gets a new token
+#[derive(Clone)]
+enum Grant {
+    ClientCredentials { secret: String },
+    // Kept alive with the refresh token, which is loaded from and saved back to the store
+    User { store: Arc<dyn TokenStore> },
+}
+
+impl fmt::Debug for Grant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Grant::ClientCredentials { .. } => write!(f, "ClientCredentials"),
+            Grant::User { .. } => write!(f, "User"),
+        }
+    }
+}
+
+/// Hands out access tokens, getting a new one from spotify shortly before the old one expires.
+///
+/// Clones share the same token, so one provider can be given to every endpoint client.
+/// The endpoint clients made from a provider also use its HTTP client, so tokens and api calls share one connection pool.
+#[derive(Debug, Clone)]
+pub struct TokenProvider {
+    id: String,
+    grant: Grant,
+    token_url: String,
+    client: Client,
+    limiter: RateLimiter,
+    refresh_margin: Duration,
+    token: Arc<Mutex<Option<Token>>>,
+}
+
+impl TokenProvider {
+    /// A provider for app tokens using the client credentials flow
+    pub fn client_credentials(id: String, secret: String) -> TokenProvider {
+        TokenProvider::with_grant(id, Grant::ClientCredentials { secret })
+    }
+
+    /// A provider for user tokens, the token is loaded from `store` and refreshed with its refresh token.
+    ///
+    /// Every new token (including a rotated refresh token) is saved back to the store, so the user only has to
+    /// log in once. The first token normally comes from [`pkce::AuthCodePkce::exchange`] and is saved with [`TokenStore::save`].
+    pub fn user<S: TokenStore + 'static>(id: String, store: S) -> TokenProvider {
+        TokenProvider::with_grant(id, Grant::User { store: Arc::new(store) })
+    }
+
+    fn with_grant(id: String, grant: Grant) -> TokenProvider {
+        TokenProvider {
+            id,
+            grant,
+            token_url: TOKEN_URL.to_string(),
+            client: Client::new(),
+            limiter: RateLimiter::new(),
+            refresh_margin: Duration::from_secs(60),
+            token: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    pub fn client_id(&self) -> &str {
+        &self.id
+    }
+
+    /// Changes where tokens are requested from, mostly useful for testing
+    pub fn token_url(mut self, url: &str) -> Self {
+        self.token_url = url.to_string();
+        self
+    }
+
+    /// Uses `client` for the token requests, and for the api calls of the endpoint clients made from this provider
+    pub fn http_client(mut self, client: Client) -> Self {
+        self.client = client;
+        self
+    }
+
+    pub(crate) fn client(&self) -> &Client {
+        &self.client
+    }
+
+    pub fn rate_limiter(mut self, limiter: RateLimiter) -> Self {
+        self.limiter = limiter;
+        self
+    }
+
+    /// How long before it expires a token gets replaced, 60 seconds by default
+    pub fn refresh_margin(mut self, margin: Duration) -> Self {
+        self.refresh_margin = margin;
+        self
+    }
+
+    /// Returns the current token, getting a new one first if there is none or it is about to expire
+    pub async fn token(&self) -> Result<Token, Error> {
+        // Held for the whole fetch so that callers waiting on the lock don't all go and get their own token
+        let mut current = self.token.lock().await;
+        if current.is_none() {
+            if let Grant::User { store } = &self.grant {
+                *current = store.load()?;
+            }
+        }
+
+        match current.as_ref() {
+            Some(token) if !token.expires_within(self.refresh_margin) => Ok(token.clone()),
+            _ => {
+                let token = match &self.grant {
+                    Grant::ClientCredentials { secret } => {
+                        request_client_credentials(&self.client, &self.token_url, &self.id, secret, &self.limiter).await?
+                    }
+                    Grant::User { store } => {
+                        let refresh_token = current.as_ref().and_then(|token| token.refresh_token.clone()).ok_or(AuthError::MissingRefreshToken)?;
+                        let token = request_refresh(&self.client, &self.token_url, &self.id, &refresh_token, &self.limiter).await?;
+                        store.save(&token)?;
+                        token
+                    }
+                };
+                *current = Some(token.clone());
+                Ok(token)
+            }
+        }
+    }
+
+    pub async fn access_token(&self) -> Result<String, Error> {
+        Ok(self.token().await?.access_token)
+    }
+}
@@ -0,0 +1,200 @@
+//! Authorization Code flow with PKCE, used to get tokens that act for a spotify user.
+//!
+//! The flow goes:
+//! 1. Start a [`RedirectListener`] with [`AuthCodePkce::listen`] so there is something to catch the redirect
+//! 2. Send the user to [`AuthCodePkce::authorize_url`] in their browser
+//! 3. Wait for spotify to redirect back with [`RedirectListener::code`]
+//! 4. Swap the code for a [`Token`] with [`AuthCodePkce::exchange`]
+//!
+//! The redirect uri (`http://127.0.0.1:<port>/callback` by default) has to be added to the app in the spotify dashboard.
+
+use super::{request_token, AuthError, Token};
+use crate::core::ratelimits::RateLimiter;
+use crate::error::Error;
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use rand::distributions::Alphanumeric;
+use rand::Rng;
+use reqwest::{Client, Url};
+use sha2::{Digest, Sha256};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+pub const ACCOUNTS_URL: &str = "https://accounts.spotify.com";
+
+const CLOSE_PAGE: &str = "<html><body><h1>All done!</h1><p>You can close this window and go back to the app.</p></body></html>";
+
+/// An Authorization Code with PKCE login, holds the verifier and state for one attempt.
+#[derive(Debug, Clone)]
+pub struct AuthCodePkce {
+    client_id: String,
+    port: u16,
+    path: String,
+    scopes: Vec<String>,
+    accounts_url: String,
+    client: Client,
+    limiter: RateLimiter,
+    verifier: String,
+    state: String,
+}
+
+impl AuthCodePkce {
+    /// Starts a new login for the app `client_id`, the redirect will be caught on `port` of the loopback address.
+    pub fn new(client_id: String, port: u16) -> AuthCodePkce {
+        AuthCodePkce {
+            client_id,
+            port,
+            path: "/callback".to_string(),
+            scopes: Vec::new(),
+            accounts_url: ACCOUNTS_URL.to_string(),
+            client: Client::new(),
+            limiter: RateLimiter::new(),
+            // The spec allows 43 to 128 characters
+            verifier: random_string(64),
+            state: random_string(16),
+        }
+    }
+
+    /// The scopes to ask the user for, see [here](https://developer.spotify.com/documentation/web-api/concepts/scopes)
+    pub fn scopes(mut self, scopes: &[&str]) -> Self {
+        self.scopes = scopes.iter().map(|scope| scope.to_string()).collect();
+        self
+    }
+
+    /// Changes the path of the redirect uri, `/callback` by default
+    pub fn redirect_path(mut self, path: &str) -> Self {
+        self.path = path.to_string();
+        self
+    }
+
+    /// Changes where the accounts service is, mostly useful for testing
+    pub fn accounts_url(mut self, url: &str) -> Self {
+        self.accounts_url = url.trim_end_matches('/').to_string();
+        self
+    }
+
+    pub fn rate_limiter(mut self, limiter: RateLimiter) -> Self {
+        self.limiter = limiter;
+        self
+    }
+
+    pub fn client_id(&self) -> &str {
+        &self.client_id
+    }
+
+    pub fn state(&self) -> &str {
+        &self.state
+    }
+
+    pub fn redirect_uri(&self) -> String {
+        format!("http://127.0.0.1:{}{}", self.port, self.path)
+    }
+
+    pub(crate) fn token_url(&self) -> String {
+        format!("{}/api/token", self.accounts_url)
+    }
+
+    /// The page the user needs to open to log in and accept the scopes, fails if [`accounts_url`](AuthCodePkce::accounts_url) was set to something that isn't a url
+    pub fn authorize_url(&self) -> Result<String, Error> {
+        let mut params = vec![
+            ("client_id", self.client_id.clone()),
+            ("response_type", "code".to_string()),
+            ("redirect_uri", self.redirect_uri()),
+            ("code_challenge_method", "S256".to_string()),
+            ("code_challenge", challenge(&self.verifier)),
+            ("state", self.state.clone()),
+        ];
+        if !self.scopes.is_empty() {
+            params.push(("scope", self.scopes.join(" ")));
+        }
+        Url::parse_with_params(&format!("{}/authorize", self.accounts_url), &params)
+            .map(String::from)
+            .map_err(|err| Error::InvalidArguments(format!("the accounts url {:?} is not a valid url: {}", self.accounts_url, err)))
+    }
+
+    /// Starts listening for the redirect, this should be done before the user is sent to the authorize url.
+    pub async fn listen(&self) -> Result<RedirectListener, Error> {
+        let listener = TcpListener::bind(("127.0.0.1", self.port)).await?;
+        Ok(RedirectListener {
+            listener,
+            path: self.path.clone(),
+            state: self.state.clone(),
+        })
+    }
+
+    /// Swaps the code from the redirect for an access and refresh token
+    pub async fn exchange(&self, code: &str) -> Result<Token, Error> {
+        let redirect_uri = self.redirect_uri();
+        let request_body = [
+            ("grant_type", "authorization_code"),
+            ("code", code),
+            ("redirect_uri", redirect_uri.as_str()),
+            ("client_id", self.client_id.as_str()),
+            ("code_verifier", self.verifier.as_str()),
+        ];
+
+        request_token(&self.client, &self.token_url(), &request_body, &self.limiter).await
+    }
+}
+
+/// Waits on the loopback port for spotify to redirect the user back.
+#[derive(Debug)]
+pub struct RedirectListener {
+    listener: TcpListener,
+    path: String,
+    state: String,
+}
+
+impl RedirectListener {
+    /// Waits for the redirect and returns the authorization code in it after checking the state matches.
+    ///
+    /// Requests to any other path (like the browser asking for a favicon) get a 404 and are ignored.
+    pub async fn code(self) -> Result<String, Error> {
+        loop {
+            let (mut stream, _) = self.listener.accept().await?;
+
+            let mut data = Vec::new();
+            let mut buf = [0u8; 1024];
+            // Only the request line is needed
+            while !data.windows(2).any(|window| window == b"\r\n") {
+                let read = stream.read(&mut buf).await?;
+                if read == 0 {
+                    break;
+                }
+                data.extend_from_slice(&buf[..read]);
+            }
+            let request = String::from_utf8_lossy(&data).to_string();
+            let target = request.split(' ').nth(1).unwrap_or_default();
+            let url = match Url::parse(&format!("http://127.0.0.1{}", target)) {
+                Ok(url) if url.path() == self.path => url,
+                _ => {
+                    stream.write_all(b"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n").await?;
+                    continue;
+                }
+            };
+
+            let response = format!("HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", CLOSE_PAGE.len(), CLOSE_PAGE);
+            stream.write_all(response.as_bytes()).await?;
+            let _ = stream.shutdown().await;
+
+            let query = |name: &str| url.query_pairs().find(|(key, _)| key == name).map(|(_, value)| value.to_string());
+            if query("state").as_deref() != Some(self.state.as_str()) {
+                return Err(AuthError::StateMismatch.into());
+            }
+            if let Some(error) = query("error") {
+                return Err(AuthError::Denied(error).into());
+            }
+            return query("code").ok_or(AuthError::MissingCode.into());
+        }
+    }
+}
+
+fn random_string(length: usize) -> String {
+    rand::thread_rng().sample_iter(&Alphanumeric).take(length).map(char::from).collect()
+}
+
+// base64url(sha256(verifier)) with no padding
+fn challenge(verifier: &str) -> String {
+    URL_SAFE_NO_PAD.encode(Sha256::digest(verifier.as_bytes()))
+}
@@ -0,0 +1,125 @@
+// Places to keep user tokens between runs so the user doesn't have to log in every time
+
+use super::Token;
+
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// Somewhere a [`Token`] (and most importantly its refresh token) can be saved and loaded from.
+///
+/// Used by [`super::TokenProvider::user`] which loads the token when it first needs one and saves every new token it gets.
+pub trait TokenStore: Send + Sync {
+    /// Returns the saved token, or `None` if nothing has been saved yet
+    fn load(&self) -> io::Result<Option<Token>>;
+
+    /// Saves the token, replacing whatever was there before
+    fn save(&self, token: &Token) -> io::Result<()>;
+}
+
+impl<T: TokenStore + ?Sized> TokenStore for Arc<T> {
+    fn load(&self) -> io::Result<Option<Token>> {
+        (**self).load()
+    }
+
+    fn save(&self, token: &Token) -> io::Result<()> {
+        (**self).save(token)
+    }
+}
+
+/// Keeps the token in memory, it is gone when the program stops.
+#[derive(Debug, Default)]
+pub struct MemoryStore {
+    token: Mutex<Option<Token>>,
+}
+
+impl MemoryStore {
+    pub fn new() -> MemoryStore {
+        MemoryStore::default()
+    }
+
+    /// A store that already has a token in it
+    pub fn with_token(token: Token) -> MemoryStore {
+        MemoryStore {
+            token: Mutex::new(Some(token)),
+        }
+    }
+
+    // The token is only ever cloned out or swapped for a whole new one, a panic can't leave half of one behind
+    fn token(&self) -> MutexGuard<'_, Option<Token>> {
+        self.token.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl TokenStore for MemoryStore {
+    fn load(&self) -> io::Result<Option<Token>> {
+        Ok(self.token().clone())
+    }
+
+    fn save(&self, token: &Token) -> io::Result<()> {
+        *self.token() = Some(token.clone());
+        Ok(())
+    }
+}
+
+/// Keeps the token in a JSON file.
+///
+/// On unix the file is only readable and writable by its owner (0600) since the refresh token works like a password.
+#[derive(Debug, Clone)]
+pub struct JsonFileStore {
+    path: PathBuf,
+}
+
+impl JsonFileStore {
+    pub fn new<P: Into<PathBuf>>(path: P) -> JsonFileStore {
+        JsonFileStore { path: path.into() }
+    }
+
+    pub fn path(&self) -> &PathBuf {
+        &self.path
+    }
+}
+
+impl TokenStore for JsonFileStore {
+    fn load(&self) -> io::Result<Option<Token>> {
+        let data = match fs::read_to_string(&self.path) {
+            Ok(data) => data,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err),
+        };
+        serde_json::from_str(&data).map(Some).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+
+    // Written to a file next to it first and then renamed over it, so a crash part way through leaves the old token
+    // instead of an empty file (spotify has already swapped the refresh token by the time it is saved)
+    fn save(&self, token: &Token) -> io::Result<()> {
+        let data = serde_json::to_string_pretty(token).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+
+        let mut name = self.path.file_name().map(|name| name.to_os_string()).unwrap_or_default();
+        name.push(".tmp");
+        let temp = self.path.with_file_name(name);
+
+        let mut options = OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        let mut file = options.open(&temp)?;
+        // mode only applies when the file is created, so fix up a temp file left behind by an earlier crash
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            file.set_permissions(fs::Permissions::from_mode(0o600))?;
+        }
+        let written = file.write_all(data.as_bytes()).and_then(|_| file.sync_all());
+        drop(file);
+        if let Err(err) = written.and_then(|_| fs::rename(&temp, &self.path)) {
+            let _ = fs::remove_file(&temp);
+            return Err(err);
+        }
+        Ok(())
+    }
+}
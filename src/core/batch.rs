@@ -0,0 +1,31 @@
+// Endpoints that look up many ids at once only take so many ids per request
+
+use super::http::Http;
+use crate::error::Error;
+
+use std::collections::HashMap;
+
+use futures::future::try_join_all;
+use serde::de::DeserializeOwned;
+
+/// Looks up `ids` at `path` in chunks of `chunk_size`, sending all of the chunks at the same time.
+///
+/// Spotify answers each chunk with `{"<key>": [...]}` holding a `null` for every id it doesn't know,
+/// the chunks are put back together so the results are in the same order as `ids`.
+pub(crate) async fn get_several<T: DeserializeOwned>(http: &Http, path: &str, key: &'static str, ids: &[String], chunk_size: usize, query: &[(&'static str, String)]) -> Result<Vec<Option<T>>, Error> {
+    let url = http.url(path);
+    let requests = ids.chunks(chunk_size).map(|chunk| {
+        let mut query = query.to_vec();
+        query.push(("ids", chunk.join(",")));
+        let url = &url;
+        async move {
+            let mut response: HashMap<String, Vec<Option<T>>> = http.get_json(url, &query).await?;
+            response.remove(key).ok_or_else(|| Error::Decode {
+                path: ".".to_string(),
+                source: serde::de::Error::missing_field(key),
+            })
+        }
+    });
+    let chunks = try_join_all(requests).await?;
+    Ok(chunks.into_iter().flatten().collect())
+}
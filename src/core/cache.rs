@@ -1,40 +1,57 @@
 use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
 
-use serde::{Serialize, Deserialize};
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// Keeps recent results so asking for the same thing again doesn't need another request.
+///
+/// Clones share the same entries, so a cache can be handed to clients running on other tasks.
+#[derive(Debug, Clone)]
 pub struct Cache<T> {
-    pub cache: HashMap<String, T>,
+    cache: Arc<Mutex<HashMap<String, T>>>,
+}
+
+// Written out so T doesn't have to be Default
+impl<T> Default for Cache<T> {
+    fn default() -> Cache<T> {
+        Cache {
+            cache: Arc::new(Mutex::new(HashMap::with_capacity(12))),
+        }
+    }
 }
 
 impl<T> Cache<T> where 
     T: Clone,
 {
     pub fn new() -> Cache<T> {
-        Cache {
-            cache: HashMap::with_capacity(12),
-        }
+        Cache::default()
     }
 
     pub fn get(&self, key: &str) -> Option<T> {
-        // Just for safety
-        if !self.cache.contains_key(key) {
-            return None;
-        }
-        let value = self.cache.get(key).expect("There was an issue getting the key? THIS IS BAD!").clone();
-        Some(value)
+        self.entries().get(key).cloned()
     }
 
-    pub fn add(&mut self, key: &str, val: T) {
-        if self.cache.len() >= 10 {
-            let last_key = self.cache.keys().last().unwrap().to_string();
-            self.cache.remove(&last_key);
-
+    pub fn add(&self, key: &str, val: T) {
+        let mut cache = self.entries();
+        if cache.len() >= 10 && !cache.contains_key(key) {
+            let last_key = cache.keys().last().unwrap().to_string();
+            cache.remove(&last_key);
         }
-        self.cache.insert(key.to_string(), val);
+        cache.insert(key.to_string(), val);
     }
 
     pub fn contains_key(&self, key: &str) -> bool {
-        self.cache.contains_key(key)
+        self.entries().contains_key(key)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries().is_empty()
+    }
+
+    // Nothing is held across an await and every change is a single insert or remove, so a poisoned lock still has good data in it
+    fn entries(&self) -> std::sync::MutexGuard<'_, HashMap<String, T>> {
+        self.cache.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
     }
 }
\ No newline at end of file
@@ -0,0 +1,42 @@
+// What every endpoint client has in common
+
+use crate::error::Error;
+
+// The constructors and `api_url` of an endpoint client, which needs a `from_http(http)` (or `from_http(http, cache)` when `Cache` is given).
+// `app` clients work with app tokens so they also get `new` and `with_rate_limiter`, `user` clients only work with a user's token
+macro_rules! GenerateConstructors {
+    (app $client:ident $(, $cache:ident)?) => {
+        pub async fn new(uid: String, secret: String) -> Result<$client, $crate::error::Error> {
+            $client::with_rate_limiter(uid, secret, $crate::core::ratelimits::RateLimiter::new()).await
+        }
+
+        /// Makes a client that sends all of its requests (including getting the token) through `limiter`.
+        ///
+        /// Clones of a [`RateLimiter`](crate::core::ratelimits::RateLimiter) share their token bucket so one limiter can be used for many clients.
+        pub async fn with_rate_limiter(uid: String, secret: String, limiter: $crate::core::ratelimits::RateLimiter) -> Result<$client, $crate::error::Error> {
+            Ok($client::from_http($crate::core::http::Http::client_credentials(uid, secret, limiter).await? $(, $cache::new())?))
+        }
+
+        GenerateConstructors!(user $client $(, $cache)?);
+    };
+
+    (user $client:ident $(, $cache:ident)?) => {
+        /// Makes a client from an existing [`TokenProvider`](crate::core::auth::TokenProvider), the token is shared with every other client using the provider
+        pub fn from_provider(tokens: $crate::core::auth::TokenProvider, limiter: $crate::core::ratelimits::RateLimiter) -> $client {
+            $client::from_http($crate::core::http::Http::new(tokens, limiter) $(, $cache::new())?)
+        }
+
+        /// Changes where requests are sent instead of `https://api.spotify.com/v1`, mostly useful for testing
+        pub fn api_url(&mut self, url: &str) -> &mut Self {
+            self.http.set_api_url(url);
+            self
+        }
+    };
+}
+
+pub(crate) use GenerateConstructors;
+
+/// Takes the request set up by the builder functions so nothing from it is left over for the next call to `run`
+pub(crate) fn take_request<R>(request: &mut Option<R>) -> Result<R, Error> {
+    request.take().ok_or_else(|| Error::InvalidArguments("no request was set up before calling run".to_string()))
+}
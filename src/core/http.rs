@@ -0,0 +1,140 @@
+// Sending requests to the web api and turning spotify's responses into structs or errors
+
+use crate::error::{ApiError, Error};
+use super::auth::TokenProvider;
+use super::ratelimits::{retry_after, RateLimiter};
+
+use reqwest::{Client, Method, Response, StatusCode};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+pub const API_URL: &str = "https://api.spotify.com/v1";
+
+/// Everything needed to make an authorized request: the HTTP client, the token provider and the rate limiter.
+#[derive(Debug, Clone)]
+pub(crate) struct Http {
+    client: Client,
+    tokens: TokenProvider,
+    limiter: RateLimiter,
+    api_url: String,
+}
+
+impl Http {
+    pub(crate) fn new(tokens: TokenProvider, limiter: RateLimiter) -> Http {
+        Http {
+            client: tokens.client().clone(),
+            tokens,
+            limiter,
+            api_url: API_URL.to_string(),
+        }
+    }
+
+    /// An Http using app tokens, the first token is fetched straight away so bad credentials show up early
+    pub(crate) async fn client_credentials(uid: String, secret: String, limiter: RateLimiter) -> Result<Http, Error> {
+        let tokens = TokenProvider::client_credentials(uid, secret).rate_limiter(limiter.clone());
+        tokens.token().await?;
+        Ok(Http::new(tokens, limiter))
+    }
+
+    pub(crate) fn set_api_url(&mut self, url: &str) {
+        self.api_url = url.trim_end_matches('/').to_string();
+    }
+
+    pub(crate) fn tokens(&self) -> &TokenProvider {
+        &self.tokens
+    }
+
+    pub(crate) fn limiter(&self) -> &RateLimiter {
+        &self.limiter
+    }
+
+    /// The full url for a path of the web api, `path` starts with a `/`
+    pub(crate) fn url(&self, path: &str) -> String {
+        format!("{}{}", self.api_url, path)
+    }
+
+    /// Sends a GET to `url` (a full url, like a `next` link) with the token and through the rate limiter
+    pub(crate) async fn get<Q: Serialize + ?Sized>(&self, url: &str, query: &Q) -> Result<Response, Error> {
+        self.send::<Q, ()>(Method::GET, url, query, None).await
+    }
+
+    /// Sends any request with an optional JSON body, with the token and through the rate limiter
+    pub(crate) async fn send<Q: Serialize + ?Sized, B: Serialize + ?Sized>(&self, method: Method, url: &str, query: &Q, body: Option<&B>) -> Result<Response, Error> {
+        let is_get = method == Method::GET;
+        let mut request = self.client.request(method, url).bearer_auth(self.tokens.access_token().await?).query(query);
+        request = match body {
+            Some(body) => request.json(body),
+            // Spotify wants a Content-Length even when there is nothing to send
+            None if !is_get => request.body(Vec::new()),
+            None => request,
+        };
+        Ok(self.limiter.send(request).await?)
+    }
+
+    /// [`send`](Http::send) for requests that answer with JSON
+    pub(crate) async fn send_json<T: DeserializeOwned, Q: Serialize + ?Sized, B: Serialize + ?Sized>(&self, method: Method, url: &str, query: &Q, body: Option<&B>) -> Result<T, Error> {
+        read_json(self.send(method, url, query, body).await?).await
+    }
+
+    /// [`send`](Http::send) for requests that answer with nothing, only the status is checked
+    pub(crate) async fn send_empty<Q: Serialize + ?Sized, B: Serialize + ?Sized>(&self, method: Method, url: &str, query: &Q, body: Option<&B>) -> Result<(), Error> {
+        check_status(self.send(method, url, query, body).await?).await.map(|_| ())
+    }
+
+    pub(crate) async fn get_json<T: DeserializeOwned, Q: Serialize + ?Sized>(&self, url: &str, query: &Q) -> Result<T, Error> {
+        read_json(self.get(url, query).await?).await
+    }
+
+    /// For endpoints that answer with no body (204) when there is nothing to give back, like the player when nothing is playing
+    pub(crate) async fn get_optional_json<T: DeserializeOwned, Q: Serialize + ?Sized>(&self, url: &str, query: &Q) -> Result<Option<T>, Error> {
+        let body = check_status(self.get(url, query).await?).await?;
+        if body.iter().all(u8::is_ascii_whitespace) {
+            return Ok(None);
+        }
+        decode(&body).map(Some)
+    }
+
+    /// Gets a full url that already has its query in it, like the `next` link of a page
+    pub(crate) async fn follow<T: DeserializeOwned>(&self, url: &str) -> Result<T, Error> {
+        self.get_json(url, &[] as &[(&str, &str)]).await
+    }
+}
+
+/// Builds a query leaving out the values that are None
+pub(crate) fn query(params: &[(&'static str, Option<String>)]) -> Vec<(&'static str, String)> {
+    params.iter().filter_map(|(key, value)| value.clone().map(|value| (*key, value))).collect()
+}
+
+#[derive(Deserialize)]
+struct ErrorBody {
+    error: ApiError,
+}
+
+/// Reads the response as JSON, or the error spotify sent back instead
+pub(crate) async fn read_json<T: DeserializeOwned>(response: Response) -> Result<T, Error> {
+    let body = check_status(response).await?;
+    decode(&body)
+}
+
+/// Returns the body of a successful response, or the error spotify sent back instead
+async fn check_status(response: Response) -> Result<Vec<u8>, Error> {
+    let status = response.status();
+    if status == StatusCode::TOO_MANY_REQUESTS {
+        return Err(Error::RateLimited { retry_after: retry_after(&response) });
+    }
+    let body = response.bytes().await?;
+    if !status.is_success() {
+        let error = serde_json::from_slice::<ErrorBody>(&body).ok().map(|body| body.error);
+        return Err(Error::Api { status: status.as_u16(), error });
+    }
+    Ok(body.to_vec())
+}
+
+/// Deserializes JSON keeping track of where in it any error happened
+pub(crate) fn decode<T: DeserializeOwned>(body: &[u8]) -> Result<T, Error> {
+    let mut deserializer = serde_json::Deserializer::from_slice(body);
+    serde_path_to_error::deserialize(&mut deserializer).map_err(|err| Error::Decode {
+        path: err.path().to_string(),
+        source: err.into_inner(),
+    })
+}
@@ -2,6 +2,14 @@ pub mod auth;
 
 pub mod cache;
 
+pub(crate) mod batch;
+
+pub(crate) mod endpoint;
+
+pub(crate) mod http;
+
+pub mod paginator;
+
 pub mod ratelimits;
 
 pub mod structs;
\ No newline at end of file
@@ -0,0 +1,117 @@
+//! Following the next links of paged results, either all at once or as a stream.
+//!
+//! Requests that spotify answers with a page only get that first page unless `all_pages(max_pages)` is added
+//! after setting them up, then the `next` link is followed until there are no more pages or `max_pages` pages
+//! have been fetched, and every page is put together into one result. It only changes the request it comes after,
+//! so it has to come after a paged request and fails with [`Error::InvalidArguments`] otherwise.
+//! The `_stream` functions go through every page one at a time instead.
+
+use super::http::Http;
+use super::structs::paging::{CursorPaging, Paging};
+use crate::error::Error;
+
+use futures::stream::{self, Stream, TryStreamExt};
+use serde::de::DeserializeOwned;
+
+/// Anything spotify sends back that is (or holds) a page linking to the next one.
+///
+/// Endpoints that wrap their page in an object (like new releases' `{"albums": {...}}`) implement this for the wrapper,
+/// since the next link gives back the wrapper too.
+pub(crate) trait Page: DeserializeOwned {
+    type Item;
+
+    fn next_url(&self) -> Option<&str>;
+
+    fn into_items(self) -> Vec<Self::Item>;
+
+    /// Adds the page after this one onto the end
+    fn concatenate(self, other: Self) -> Self;
+}
+
+impl<T: DeserializeOwned> Page for Paging<T> {
+    type Item = T;
+
+    fn next_url(&self) -> Option<&str> {
+        self.next.as_deref()
+    }
+
+    fn into_items(self) -> Vec<T> {
+        self.items
+    }
+
+    fn concatenate(self, other: Paging<T>) -> Self {
+        Paging::concatenate(self, other)
+    }
+}
+
+impl<T: DeserializeOwned> Page for CursorPaging<T> {
+    type Item = T;
+
+    fn next_url(&self) -> Option<&str> {
+        self.next.as_deref()
+    }
+
+    fn into_items(self) -> Vec<T> {
+        self.items
+    }
+
+    fn concatenate(self, other: CursorPaging<T>) -> Self {
+        CursorPaging::concatenate(self, other)
+    }
+}
+
+/// A request where some kinds are answered with pages, each of those keeping how many pages to fetch
+pub(crate) trait PagedRequest {
+    /// The number of pages to fetch, None for kinds of request that only ever have one page
+    fn max_pages(&mut self) -> Option<&mut usize>;
+}
+
+/// Sets how many pages `request` fetches, failing if there is no request or it doesn't have pages
+pub(crate) fn all_pages<R: PagedRequest>(request: Option<&mut R>, max_pages: usize) -> Result<(), Error> {
+    match request.and_then(PagedRequest::max_pages) {
+        Some(pages) => {
+            *pages = max_pages;
+            Ok(())
+        }
+        None => Err(Error::InvalidArguments("all_pages has to come after setting up a request that gives back pages".to_string())),
+    }
+}
+
+/// Streams the items of every page, starting at `url` with `query`.
+///
+/// Each page is only fetched once the items from the page before it have been used up, and every request
+/// goes through the rate limiter of `http`.
+pub(crate) fn paginate<P>(http: Http, url: String, query: Vec<(&'static str, String)>) -> impl Stream<Item = Result<P::Item, Error>>
+where
+    P: Page,
+{
+    stream::try_unfold(Some((url, query)), move |next| {
+        let http = http.clone();
+        async move {
+            let (url, query) = match next {
+                Some(next) => next,
+                None => return Ok::<_, Error>(None),
+            };
+            let page = http.get_json::<P, _>(&url, &query).await?;
+            // The next link already has all of the query in it
+            let next = page.next_url().map(|next| (next.to_string(), Vec::new()));
+            Ok(Some((stream::iter(page.into_items().into_iter().map(Ok)), next)))
+        }
+    })
+    .try_flatten()
+}
+
+/// Follows the next links of `page` adding each one on, until there are none left or there are `max_pages` pages
+pub(crate) async fn fetch_pages<P: Page>(http: &Http, mut page: P, max_pages: usize) -> Result<P, Error> {
+    let mut pages = 1;
+    while pages < max_pages {
+        let url = match page.next_url() {
+            Some(url) => url.to_string(),
+            None => break,
+        };
+        let next = http.follow::<P>(&url).await?;
+        page = page.concatenate(next);
+        pages += 1;
+    }
+    Ok(page)
+}
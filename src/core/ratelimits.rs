@@ -0,0 +1,172 @@
+//! Rate limiting for the requests made to spotify.
+//!
+//! Spotify works out its rate limit over a rolling 30 second window and answers with a `429 Too Many Requests`
+//! (and a `Retry-After` header in seconds) when it is hit. The [`RateLimiter`] waits and retries those requests,
+//! and can also hold a client side [`TokenBucket`] so that big batch jobs slow themselves down before spotify has to.
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use reqwest::{header::RETRY_AFTER, RequestBuilder, Response, StatusCode};
+
+/// How many times, and how long to wait between, a rate limited request is retried.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Retries after the first attempt, 0 turns retrying off
+    pub max_retries: u32,
+    /// Wait used for the first retry when spotify does not send a `Retry-After`, doubled on every retry after that
+    pub base_delay: Duration,
+    /// Longest that will ever be waited between two attempts, even if `Retry-After` asks for more
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> RetryPolicy {
+        RetryPolicy {
+            max_retries: 3,
+            base_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(60),
+        }
+    }
+}
+
+impl RetryPolicy {
+    // The wait before retry number `attempt` (starting at 0)
+    fn delay(&self, attempt: u32, retry_after: Option<Duration>) -> Duration {
+        let delay = match retry_after {
+            Some(retry_after) => retry_after,
+            None => self.base_delay.saturating_mul(2u32.saturating_pow(attempt)),
+        };
+        delay.min(self.max_delay)
+    }
+}
+
+/// A client side token bucket, every request takes one token and tokens refill at a steady rate.
+#[derive(Debug)]
+pub struct TokenBucket {
+    capacity: f64,
+    tokens: f64,
+    // Tokens added per second
+    refill: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    /// Allows `capacity` requests every `window`, starting full.
+    pub fn new(capacity: u32, window: Duration) -> TokenBucket {
+        let capacity = f64::from(capacity.max(1));
+        TokenBucket {
+            capacity,
+            tokens: capacity,
+            refill: capacity / window.as_secs_f64().max(f64::EPSILON),
+            last_refill: Instant::now(),
+        }
+    }
+
+    fn refill(&mut self) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.refill).min(self.capacity);
+        self.last_refill = now;
+    }
+
+    /// Takes a token if there is one, otherwise returns how long until the next one is ready.
+    pub fn try_take(&mut self) -> Result<(), Duration> {
+        self.refill();
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            Ok(())
+        }
+        else {
+            Err(Duration::from_secs_f64((1.0 - self.tokens) / self.refill))
+        }
+    }
+}
+
+/// Sends requests while keeping to spotify's rate limits.
+///
+/// Cloning a `RateLimiter` is cheap and the clones share the same token bucket.
+#[derive(Debug, Clone, Default)]
+pub struct RateLimiter {
+    policy: RetryPolicy,
+    bucket: Option<Arc<Mutex<TokenBucket>>>,
+}
+
+impl RateLimiter {
+    /// A rate limiter that retries with the default [`RetryPolicy`] and has no token bucket.
+    pub fn new() -> RateLimiter {
+        RateLimiter::default()
+    }
+
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.policy.max_retries = max_retries;
+        self
+    }
+
+    /// Only lets `capacity` requests through every `window`, anything over that waits for the bucket to refill.
+    pub fn token_bucket(mut self, capacity: u32, window: Duration) -> Self {
+        self.bucket = Some(Arc::new(Mutex::new(TokenBucket::new(capacity, window))));
+        self
+    }
+
+    pub fn policy(&self) -> RetryPolicy {
+        self.policy
+    }
+
+    /// Waits until the token bucket lets another request through, returns straight away if there is no bucket.
+    pub async fn acquire(&self) {
+        let bucket = match &self.bucket {
+            Some(bucket) => bucket,
+            None => return,
+        };
+        loop {
+            // The lock is never held over the sleep. A poisoned bucket is still usable, the worst a panic in `try_take`
+            // can do is leave one token too many or too few
+            let wait = match bucket.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).try_take() {
+                Ok(()) => return,
+                Err(wait) => wait,
+            };
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Sends the request, waiting on the token bucket first and retrying on a `429` until the policy runs out.
+    ///
+    /// If the request is still rate limited after the last retry the `429` response is returned.
+    pub async fn send(&self, request: RequestBuilder) -> Result<Response, reqwest::Error> {
+        let mut attempt = 0;
+        loop {
+            self.acquire().await;
+            // Requests with a streaming body can't be cloned so they only get the one try
+            let retry = match request.try_clone() {
+                Some(retry) if attempt < self.policy.max_retries => retry,
+                _ => return request.send().await,
+            };
+
+            let response = retry.send().await?;
+            if response.status() != StatusCode::TOO_MANY_REQUESTS {
+                return Ok(response);
+            }
+            tokio::time::sleep(self.policy.delay(attempt, retry_after(&response))).await;
+            attempt += 1;
+        }
+    }
+}
+
+/// Reads the `Retry-After` header (in seconds) from a response.
+pub fn retry_after(response: &Response) -> Option<Duration> {
+    response
+        .headers()
+        .get(RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .map(Duration::from_secs)
+}
@@ -0,0 +1,110 @@
+use super::artist_structs::{Album, Artist, ExternalIds, ExternalUrls, Image, LinkedFrom, Restrictions};
+use super::paging::Paging;
+use crate::core::paginator::Page;
+
+use serde::{Deserialize, Serialize};
+
+/// Everything about an album, what the album endpoint gives back.
+///
+/// [`Album`] is the shorter version of this used inside of other objects (tracks, new releases, etc).
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FullAlbum {
+    #[serde(rename = "album_type")]
+    pub album_type: String,
+    #[serde(rename = "total_tracks")]
+    pub total_tracks: i32,
+    #[serde(rename = "available_markets")]
+    pub available_markets: Option<Vec<String>>,
+    #[serde(rename = "external_urls")]
+    pub external_urls: ExternalUrls,
+    pub href: String,
+    pub id: String,
+    pub images: Vec<Image>,
+    pub name: String,
+    #[serde(rename = "release_date")]
+    pub release_date: String,
+    #[serde(rename = "release_date_precision")]
+    pub release_date_precision: String,
+    pub restrictions: Option<Restrictions>,
+    #[serde(rename = "type")]
+    pub type_field: String,
+    pub uri: String,
+    pub artists: Vec<Artist>,
+    pub tracks: Paging<SimplifiedTrack>,
+    pub copyrights: Vec<Copyright>,
+    #[serde(rename = "external_ids")]
+    pub external_ids: ExternalIds,
+    pub genres: Vec<String>,
+    pub label: String,
+    pub popularity: i32,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Copyright {
+    pub text: String,
+    /// C for the copyright, P for the sound recording (performance) copyright
+    #[serde(rename = "type")]
+    pub type_field: String,
+}
+
+/// A track without its album, used when the album is already known (like the tracks of an album)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimplifiedTrack {
+    pub artists: Vec<Artist>,
+    #[serde(rename = "available_markets")]
+    pub available_markets: Option<Vec<String>>,
+    #[serde(rename = "disc_number")]
+    pub disc_number: i32,
+    #[serde(rename = "duration_ms")]
+    pub duration_ms: i32,
+    pub explicit: bool,
+    #[serde(rename = "external_urls")]
+    pub external_urls: ExternalUrls,
+    pub href: String,
+    pub id: String,
+    #[serde(rename = "is_playable")]
+    pub is_playable: Option<bool>,
+    #[serde(rename = "linked_from")]
+    pub linked_from: Option<LinkedFrom>,
+    pub restrictions: Option<Restrictions>,
+    pub name: String,
+    #[serde(rename = "preview_url")]
+    pub preview_url: Option<String>,
+    #[serde(rename = "track_number")]
+    pub track_number: i32,
+    #[serde(rename = "type")]
+    pub type_field: String,
+    pub uri: String,
+    #[serde(rename = "is_local")]
+    pub is_local: bool,
+}
+
+/// A page of the tracks on an album
+pub type AlbumTracks = Paging<SimplifiedTrack>;
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewReleases {
+    pub albums: Paging<Album>,
+}
+
+impl Page for NewReleases {
+    type Item = Album;
+
+    fn next_url(&self) -> Option<&str> {
+        self.albums.next.as_deref()
+    }
+
+    fn into_items(self) -> Vec<Album> {
+        self.albums.items
+    }
+
+    fn concatenate(self, other: NewReleases) -> Self {
+        NewReleases {
+            albums: self.albums.concatenate(other.albums),
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use super::paging::Paging;
+
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -26,6 +28,8 @@ pub struct Artist {
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ExternalUrls {
+    /// Empty for things that aren't on spotify, like local files in a playlist
+    #[serde(default)]
     pub spotify: String,
 }
 
@@ -44,17 +48,8 @@ pub struct Image {
     pub width: Option<i32>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct Albums {
-    pub href: String,
-    pub limit: i32,
-    pub next: Option<String>,
-    pub offset: i32,
-    pub previous: Option<String>,
-    pub total: i32,
-    pub items: Vec<Item>,
-}
+/// A page of an artist's albums
+pub type Albums = Paging<Item>;
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -107,8 +102,9 @@ pub struct Track {
     pub external_urls: ExternalUrls,
     pub href: String,
     pub id: String,
+    /// Only sent when a market was given
     #[serde(rename = "is_playable")]
-    pub is_playable: bool,
+    pub is_playable: Option<bool>,
     #[serde(rename = "linked_from")]
     pub linked_from: Option<LinkedFrom>,
     pub restrictions: Option<Restrictions>,
@@ -160,7 +156,7 @@ pub struct Restrictions {
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ExternalIds {
-    pub isrc: String,
+    pub isrc: Option<String>,
     pub ean: Option<String>,
     pub upc: Option<String>,
 }
@@ -168,4 +164,11 @@ pub struct ExternalIds {
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct LinkedFrom {
+    #[serde(rename = "external_urls")]
+    pub external_urls: Option<ExternalUrls>,
+    pub href: Option<String>,
+    pub id: Option<String>,
+    #[serde(rename = "type")]
+    pub type_field: Option<String>,
+    pub uri: Option<String>,
 }
@@ -0,0 +1,172 @@
+use super::artist_structs::Track;
+
+use serde::{Deserialize, Serialize};
+
+/// What a track sounds like, as worked out by spotify
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AudioFeatures {
+    /// From 0.0 to 1.0, how sure spotify is that the track is acoustic
+    pub acousticness: f32,
+    #[serde(rename = "analysis_url")]
+    pub analysis_url: String,
+    pub danceability: f32,
+    #[serde(rename = "duration_ms")]
+    pub duration_ms: i32,
+    pub energy: f32,
+    pub id: String,
+    pub instrumentalness: f32,
+    /// The key in pitch class notation (0 = C, 1 = C♯/D♭, ...), -1 if no key was found
+    pub key: i32,
+    pub liveness: f32,
+    /// The average loudness in decibels, usually between -60 and 0
+    pub loudness: f32,
+    /// 1 for major, 0 for minor
+    pub mode: i32,
+    pub speechiness: f32,
+    /// Beats per minute
+    pub tempo: f32,
+    /// How many beats are in each bar, from 3 to 7
+    #[serde(rename = "time_signature")]
+    pub time_signature: i32,
+    #[serde(rename = "track_href")]
+    pub track_href: String,
+    #[serde(rename = "type")]
+    pub type_field: String,
+    pub uri: String,
+    /// From 0.0 to 1.0, how positive the track sounds
+    pub valence: f32,
+}
+
+/// The low level analysis of a track's structure and musical content
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AudioAnalysis {
+    pub meta: AnalysisMeta,
+    pub track: AnalysisTrack,
+    pub bars: Vec<TimeInterval>,
+    pub beats: Vec<TimeInterval>,
+    pub sections: Vec<Section>,
+    pub segments: Vec<Segment>,
+    pub tatums: Vec<TimeInterval>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnalysisMeta {
+    #[serde(rename = "analyzer_version")]
+    pub analyzer_version: Option<String>,
+    pub platform: Option<String>,
+    #[serde(rename = "detailed_status")]
+    pub detailed_status: Option<String>,
+    #[serde(rename = "status_code")]
+    pub status_code: Option<i32>,
+    pub timestamp: Option<i64>,
+    #[serde(rename = "analysis_time")]
+    pub analysis_time: Option<f32>,
+    #[serde(rename = "input_process")]
+    pub input_process: Option<String>,
+}
+
+/// The analysis of the whole track
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnalysisTrack {
+    #[serde(rename = "num_samples")]
+    pub num_samples: Option<i64>,
+    /// In seconds
+    pub duration: f32,
+    #[serde(rename = "end_of_fade_in")]
+    pub end_of_fade_in: Option<f32>,
+    #[serde(rename = "start_of_fade_out")]
+    pub start_of_fade_out: Option<f32>,
+    pub loudness: f32,
+    pub tempo: f32,
+    #[serde(rename = "tempo_confidence")]
+    pub tempo_confidence: f32,
+    #[serde(rename = "time_signature")]
+    pub time_signature: i32,
+    #[serde(rename = "time_signature_confidence")]
+    pub time_signature_confidence: f32,
+    pub key: i32,
+    #[serde(rename = "key_confidence")]
+    pub key_confidence: f32,
+    pub mode: i32,
+    #[serde(rename = "mode_confidence")]
+    pub mode_confidence: f32,
+}
+
+/// A bar, beat or tatum, the times are in seconds
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeInterval {
+    pub start: f32,
+    pub duration: f32,
+    pub confidence: f32,
+}
+
+/// A large part of the track like a chorus or verse
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Section {
+    pub start: f32,
+    pub duration: f32,
+    pub confidence: f32,
+    pub loudness: f32,
+    pub tempo: f32,
+    #[serde(rename = "tempo_confidence")]
+    pub tempo_confidence: f32,
+    pub key: i32,
+    #[serde(rename = "key_confidence")]
+    pub key_confidence: f32,
+    pub mode: i32,
+    #[serde(rename = "mode_confidence")]
+    pub mode_confidence: f32,
+    #[serde(rename = "time_signature")]
+    pub time_signature: i32,
+    #[serde(rename = "time_signature_confidence")]
+    pub time_signature_confidence: f32,
+}
+
+/// A short part of the track where the sound stays about the same
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Segment {
+    pub start: f32,
+    pub duration: f32,
+    pub confidence: f32,
+    #[serde(rename = "loudness_start")]
+    pub loudness_start: f32,
+    #[serde(rename = "loudness_max")]
+    pub loudness_max: f32,
+    #[serde(rename = "loudness_max_time")]
+    pub loudness_max_time: f32,
+    #[serde(rename = "loudness_end")]
+    pub loudness_end: Option<f32>,
+    /// How strong each of the 12 pitch classes are, from 0.0 to 1.0
+    pub pitches: Vec<f32>,
+    /// The 12 timbre values of the segment
+    pub timbre: Vec<f32>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Recommendations {
+    pub seeds: Vec<RecommendationSeed>,
+    pub tracks: Vec<Track>,
+}
+
+/// How many tracks were found for one of the seeds
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecommendationSeed {
+    pub after_filtering_size: i32,
+    pub after_relinking_size: i32,
+    /// Null for genre seeds
+    pub href: Option<String>,
+    pub id: String,
+    pub initial_pool_size: i32,
+    /// "artist", "track" or "genre"
+    #[serde(rename = "type")]
+    pub type_field: String,
+}
@@ -0,0 +1,128 @@
+use super::album_structs::Copyright;
+use super::artist_structs::{ExternalUrls, Image, Restrictions};
+use super::paging::Paging;
+use super::show_structs::{ReleaseDatePrecision, ResumePoint};
+
+use serde::{Deserialize, Serialize};
+
+/// Everything about an audiobook, what the audiobook endpoint gives back.
+///
+/// [`Audiobook`] is the shorter version of this used inside of other objects (chapters, saved audiobooks, etc).
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FullAudiobook {
+    pub authors: Vec<Author>,
+    #[serde(rename = "available_markets")]
+    pub available_markets: Option<Vec<String>>,
+    pub copyrights: Vec<Copyright>,
+    pub description: String,
+    #[serde(rename = "html_description")]
+    pub html_description: Option<String>,
+    /// Like "Unabridged"
+    pub edition: Option<String>,
+    pub explicit: bool,
+    #[serde(rename = "external_urls")]
+    pub external_urls: ExternalUrls,
+    pub href: String,
+    pub id: String,
+    pub images: Vec<Image>,
+    pub languages: Vec<String>,
+    #[serde(rename = "media_type")]
+    pub media_type: String,
+    pub name: String,
+    pub narrators: Vec<Narrator>,
+    pub publisher: String,
+    #[serde(rename = "type")]
+    pub type_field: String,
+    pub uri: String,
+    #[serde(rename = "total_chapters")]
+    pub total_chapters: Option<i32>,
+    pub chapters: AudiobookChapters,
+}
+
+/// A page of the chapters of an audiobook
+pub type AudiobookChapters = Paging<Chapter>;
+
+/// An audiobook without its chapters
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Audiobook {
+    pub authors: Vec<Author>,
+    #[serde(rename = "available_markets")]
+    pub available_markets: Option<Vec<String>>,
+    pub copyrights: Vec<Copyright>,
+    pub description: String,
+    #[serde(rename = "html_description")]
+    pub html_description: Option<String>,
+    pub edition: Option<String>,
+    pub explicit: bool,
+    #[serde(rename = "external_urls")]
+    pub external_urls: ExternalUrls,
+    pub href: String,
+    pub id: String,
+    pub images: Vec<Image>,
+    pub languages: Vec<String>,
+    #[serde(rename = "media_type")]
+    pub media_type: String,
+    pub name: String,
+    pub narrators: Vec<Narrator>,
+    pub publisher: String,
+    #[serde(rename = "type")]
+    pub type_field: String,
+    pub uri: String,
+    #[serde(rename = "total_chapters")]
+    pub total_chapters: Option<i32>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Author {
+    pub name: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Narrator {
+    pub name: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Chapter {
+    #[serde(rename = "audio_preview_url")]
+    pub audio_preview_url: Option<String>,
+    #[serde(rename = "available_markets")]
+    pub available_markets: Option<Vec<String>>,
+    #[serde(rename = "chapter_number")]
+    pub chapter_number: i32,
+    pub description: String,
+    #[serde(rename = "html_description")]
+    pub html_description: Option<String>,
+    #[serde(rename = "duration_ms")]
+    pub duration_ms: i32,
+    pub explicit: bool,
+    #[serde(rename = "external_urls")]
+    pub external_urls: ExternalUrls,
+    pub href: String,
+    pub id: String,
+    pub images: Vec<Image>,
+    /// Only sent when a market was given
+    #[serde(rename = "is_playable")]
+    pub is_playable: Option<bool>,
+    pub languages: Vec<String>,
+    pub name: String,
+    #[serde(rename = "release_date")]
+    pub release_date: String,
+    #[serde(rename = "release_date_precision")]
+    pub release_date_precision: ReleaseDatePrecision,
+    /// Where the user stopped listening, needs a user token with the `user-read-playback-position` scope
+    #[serde(rename = "resume_point")]
+    pub resume_point: Option<ResumePoint>,
+    /// Why the chapter can't be played, like "market" when it isn't available where the user is
+    pub restrictions: Option<Restrictions>,
+    #[serde(rename = "type")]
+    pub type_field: String,
+    pub uri: String,
+    /// Not there when the audiobook is already known, like the chapters of an audiobook
+    pub audiobook: Option<Audiobook>,
+}
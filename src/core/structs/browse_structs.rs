@@ -0,0 +1,79 @@
+use super::artist_structs::Image;
+use super::paging::Paging;
+use super::playlist_structs::SimplifiedPlaylist;
+use crate::core::paginator::Page;
+
+use serde::{Deserialize, Serialize};
+
+/// A category used to tag items on spotify, like "Workout" or "Chill"
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Category {
+    pub href: String,
+    pub icons: Vec<Image>,
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Categories {
+    pub categories: Paging<Category>,
+}
+
+/// A page of playlists from browse along with the message spotify shows above them, like "Monday morning music, coming right up!"
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BrowsePlaylists {
+    pub message: Option<String>,
+    pub playlists: Paging<SimplifiedPlaylist>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Markets {
+    pub markets: Vec<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GenreSeeds {
+    pub genres: Vec<String>,
+}
+
+impl Page for Categories {
+    type Item = Category;
+
+    fn next_url(&self) -> Option<&str> {
+        self.categories.next.as_deref()
+    }
+
+    fn into_items(self) -> Vec<Category> {
+        self.categories.items
+    }
+
+    fn concatenate(self, other: Categories) -> Self {
+        Categories {
+            categories: self.categories.concatenate(other.categories),
+        }
+    }
+}
+
+impl Page for BrowsePlaylists {
+    type Item = SimplifiedPlaylist;
+
+    fn next_url(&self) -> Option<&str> {
+        self.playlists.next.as_deref()
+    }
+
+    fn into_items(self) -> Vec<SimplifiedPlaylist> {
+        self.playlists.items
+    }
+
+    fn concatenate(self, other: BrowsePlaylists) -> Self {
+        BrowsePlaylists {
+            message: self.message,
+            playlists: self.playlists.concatenate(other.playlists),
+        }
+    }
+}
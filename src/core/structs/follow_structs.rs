@@ -0,0 +1,30 @@
+use super::artist_structs::Artist;
+use super::paging::CursorPaging;
+use crate::core::paginator::Page;
+
+use serde::{Deserialize, Serialize};
+
+/// The artists the current user follows, spotify wraps the page in an object
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FollowedArtists {
+    pub artists: CursorPaging<Artist>,
+}
+
+impl Page for FollowedArtists {
+    type Item = Artist;
+
+    fn next_url(&self) -> Option<&str> {
+        self.artists.next.as_deref()
+    }
+
+    fn into_items(self) -> Vec<Artist> {
+        self.artists.items
+    }
+
+    fn concatenate(self, other: FollowedArtists) -> Self {
+        FollowedArtists {
+            artists: self.artists.concatenate(other.artists),
+        }
+    }
+}
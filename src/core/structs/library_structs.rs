@@ -0,0 +1,38 @@
+use super::album_structs::FullAlbum;
+use super::artist_structs::Track;
+use super::show_structs::{Episode, Show};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SavedTrack {
+    /// When the track was saved, as an ISO 8601 timestamp
+    #[serde(rename = "added_at")]
+    pub added_at: String,
+    pub track: Track,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SavedAlbum {
+    #[serde(rename = "added_at")]
+    pub added_at: String,
+    pub album: FullAlbum,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SavedShow {
+    #[serde(rename = "added_at")]
+    pub added_at: String,
+    pub show: Show,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SavedEpisode {
+    #[serde(rename = "added_at")]
+    pub added_at: String,
+    pub episode: Episode,
+}
@@ -1,3 +1,25 @@
 pub mod user_struct;
 
-pub mod artist_structs;
\ No newline at end of file
+pub mod artist_structs;
+
+pub mod album_structs;
+
+pub mod playlist_structs;
+
+pub mod search_structs;
+
+pub mod show_structs;
+
+pub mod audiobook_structs;
+
+pub mod library_structs;
+
+pub mod follow_structs;
+
+pub mod player_structs;
+
+pub mod browse_structs;
+
+pub mod audio_structs;
+
+pub mod paging;
\ No newline at end of file
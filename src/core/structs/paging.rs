@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+
+/// A page of results, used by every endpoint that returns a list with an offset.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Paging<T> {
+    pub href: String,
+    pub limit: i32,
+    pub next: Option<String>,
+    pub offset: i32,
+    pub previous: Option<String>,
+    pub total: i32,
+    pub items: Vec<T>,
+}
+
+/// A page of results that uses cursors instead of an offset (followed artists, recently played).
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CursorPaging<T> {
+    pub href: String,
+    pub limit: i32,
+    pub next: Option<String>,
+    pub cursors: Option<Cursors>,
+    pub total: Option<i32>,
+    pub items: Vec<T>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Cursors {
+    pub after: Option<String>,
+    pub before: Option<String>,
+}
+
+impl<T> Paging<T> {
+    // Adds the page after this one onto the end
+    pub(crate) fn concatenate(mut self, mut other: Paging<T>) -> Self {
+        self.items.append(&mut other.items);
+        // Every page has the same total, it is the number of items across all of the pages
+        self.total = other.total;
+        // Once the last page has been added there is no next page left
+        self.next = other.next;
+        self
+    }
+}
+
+impl<T> CursorPaging<T> {
+    // Adds the page after this one onto the end
+    pub(crate) fn concatenate(mut self, mut other: CursorPaging<T>) -> Self {
+        self.items.append(&mut other.items);
+        self.total = other.total.or(self.total);
+        self.next = other.next;
+        self.cursors = other.cursors;
+        self
+    }
+}
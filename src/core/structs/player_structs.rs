@@ -0,0 +1,191 @@
+use super::artist_structs::{ExternalUrls, Track};
+use super::show_structs::Episode;
+
+use serde::{Deserialize, Serialize};
+
+/// Everything about the current playback, what the player endpoint gives back
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaybackState {
+    pub device: Device,
+    #[serde(rename = "repeat_state")]
+    pub repeat_state: RepeatState,
+    #[serde(rename = "shuffle_state")]
+    pub shuffle_state: bool,
+    pub context: Option<Context>,
+    /// When the state was last changed, in milliseconds since the unix epoch
+    pub timestamp: i64,
+    #[serde(rename = "progress_ms")]
+    pub progress_ms: Option<i64>,
+    #[serde(rename = "is_playing")]
+    pub is_playing: bool,
+    pub item: Option<PlayingItem>,
+    #[serde(rename = "currently_playing_type")]
+    pub currently_playing_type: CurrentlyPlayingType,
+    pub actions: Option<Actions>,
+}
+
+/// The playback state without the device, repeat and shuffle state
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CurrentlyPlaying {
+    pub context: Option<Context>,
+    pub timestamp: i64,
+    #[serde(rename = "progress_ms")]
+    pub progress_ms: Option<i64>,
+    #[serde(rename = "is_playing")]
+    pub is_playing: bool,
+    pub item: Option<PlayingItem>,
+    #[serde(rename = "currently_playing_type")]
+    pub currently_playing_type: CurrentlyPlayingType,
+    pub actions: Option<Actions>,
+}
+
+/// Something that can be played, a track or a podcast episode
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PlayingItem {
+    Track(Box<Track>),
+    Episode(Box<Episode>),
+}
+
+impl PlayingItem {
+    pub fn id(&self) -> &str {
+        match self {
+            PlayingItem::Track(track) => &track.id,
+            PlayingItem::Episode(episode) => &episode.id,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            PlayingItem::Track(track) => &track.name,
+            PlayingItem::Episode(episode) => &episode.name,
+        }
+    }
+
+    pub fn uri(&self) -> &str {
+        match self {
+            PlayingItem::Track(track) => &track.uri,
+            PlayingItem::Episode(episode) => &episode.uri,
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CurrentlyPlayingType {
+    Track,
+    Episode,
+    Ad,
+    #[default]
+    Unknown,
+}
+
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RepeatState {
+    #[default]
+    Off,
+    /// Repeat the current track
+    Track,
+    /// Repeat the current album, playlist, etc
+    Context,
+}
+
+impl RepeatState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RepeatState::Off => "off",
+            RepeatState::Track => "track",
+            RepeatState::Context => "context",
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Device {
+    /// Can be null, and isn't always the same between requests
+    pub id: Option<String>,
+    #[serde(rename = "is_active")]
+    pub is_active: bool,
+    #[serde(rename = "is_private_session")]
+    pub is_private_session: bool,
+    /// If the device can't be controlled through the web api
+    #[serde(rename = "is_restricted")]
+    pub is_restricted: bool,
+    pub name: String,
+    /// "computer", "smartphone", "speaker", etc
+    #[serde(rename = "type")]
+    pub type_field: String,
+    #[serde(rename = "volume_percent")]
+    pub volume_percent: Option<i32>,
+    #[serde(rename = "supports_volume")]
+    pub supports_volume: Option<bool>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Devices {
+    pub devices: Vec<Device>,
+}
+
+/// What is being played from, an album, artist, playlist or show
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Context {
+    #[serde(rename = "type")]
+    pub type_field: String,
+    pub href: Option<String>,
+    #[serde(rename = "external_urls")]
+    pub external_urls: Option<ExternalUrls>,
+    pub uri: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Actions {
+    pub disallows: Option<Disallows>,
+}
+
+/// The actions that can't be done right now, anything that isn't Some(true) is allowed
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Disallows {
+    #[serde(rename = "interrupting_playback")]
+    pub interrupting_playback: Option<bool>,
+    pub pausing: Option<bool>,
+    pub resuming: Option<bool>,
+    pub seeking: Option<bool>,
+    #[serde(rename = "skipping_next")]
+    pub skipping_next: Option<bool>,
+    #[serde(rename = "skipping_prev")]
+    pub skipping_prev: Option<bool>,
+    #[serde(rename = "toggling_repeat_context")]
+    pub toggling_repeat_context: Option<bool>,
+    #[serde(rename = "toggling_shuffle")]
+    pub toggling_shuffle: Option<bool>,
+    #[serde(rename = "toggling_repeat_track")]
+    pub toggling_repeat_track: Option<bool>,
+    #[serde(rename = "transferring_playback")]
+    pub transferring_playback: Option<bool>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Queue {
+    #[serde(rename = "currently_playing")]
+    pub currently_playing: Option<PlayingItem>,
+    pub queue: Vec<PlayingItem>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayHistory {
+    pub track: Track,
+    /// When the track was played, as an ISO 8601 timestamp
+    #[serde(rename = "played_at")]
+    pub played_at: String,
+    pub context: Option<Context>,
+}
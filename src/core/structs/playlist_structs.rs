@@ -0,0 +1,175 @@
+use super::artist_structs::{ExternalIds, ExternalUrls, Followers, Image, LinkedFrom, Restrictions};
+use super::paging::Paging;
+
+use serde::{Deserialize, Serialize};
+
+/// A page of the items in a playlist
+pub type PlaylistItems = Paging<PlaylistItem>;
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Playlist {
+    pub collaborative: bool,
+    pub description: Option<String>,
+    #[serde(rename = "external_urls")]
+    pub external_urls: ExternalUrls,
+    pub followers: Option<Followers>,
+    pub href: String,
+    pub id: String,
+    pub images: Option<Vec<Image>>,
+    pub name: String,
+    pub owner: PlaylistOwner,
+    /// None when the playlist's status isn't relevant, like for other users' playlists
+    pub public: Option<bool>,
+    /// The version of the playlist, changes every time the playlist does
+    #[serde(rename = "snapshot_id")]
+    pub snapshot_id: String,
+    pub tracks: PlaylistItems,
+    #[serde(rename = "type")]
+    pub type_field: String,
+    pub uri: String,
+}
+
+/// A playlist as it shows up in lists of playlists, without its items
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimplifiedPlaylist {
+    pub collaborative: bool,
+    pub description: Option<String>,
+    #[serde(rename = "external_urls")]
+    pub external_urls: ExternalUrls,
+    pub href: String,
+    pub id: String,
+    pub images: Option<Vec<Image>>,
+    pub name: String,
+    pub owner: PlaylistOwner,
+    pub public: Option<bool>,
+    #[serde(rename = "snapshot_id")]
+    pub snapshot_id: String,
+    pub tracks: PlaylistTracksRef,
+    #[serde(rename = "type")]
+    pub type_field: String,
+    pub uri: String,
+}
+
+/// Where to get a playlist's items and how many there are
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaylistTracksRef {
+    pub href: String,
+    pub total: i32,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaylistOwner {
+    #[serde(rename = "display_name")]
+    pub display_name: Option<String>,
+    #[serde(rename = "external_urls")]
+    pub external_urls: ExternalUrls,
+    pub followers: Option<Followers>,
+    pub href: String,
+    pub id: String,
+    #[serde(rename = "type")]
+    pub type_field: String,
+    pub uri: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaylistItem {
+    /// Null for very old playlists
+    #[serde(rename = "added_at")]
+    pub added_at: Option<String>,
+    #[serde(rename = "added_by")]
+    pub added_by: Option<PlaylistOwner>,
+    #[serde(rename = "is_local")]
+    pub is_local: bool,
+    /// None when the track is no longer on spotify
+    pub track: Option<PlaylistTrack>,
+}
+
+/// A track in a playlist.
+///
+/// Playlists can have local files in them (`is_local`), spotify doesn't know about those so they have no id, href or album details.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaylistTrack {
+    pub album: PlaylistTrackAlbum,
+    pub artists: Vec<PlaylistTrackArtist>,
+    #[serde(rename = "available_markets")]
+    pub available_markets: Option<Vec<String>>,
+    #[serde(rename = "disc_number")]
+    pub disc_number: i32,
+    #[serde(rename = "duration_ms")]
+    pub duration_ms: i32,
+    pub explicit: bool,
+    #[serde(rename = "external_ids")]
+    pub external_ids: ExternalIds,
+    #[serde(rename = "external_urls")]
+    pub external_urls: ExternalUrls,
+    pub href: Option<String>,
+    pub id: Option<String>,
+    #[serde(rename = "is_playable")]
+    pub is_playable: Option<bool>,
+    #[serde(rename = "linked_from")]
+    pub linked_from: Option<LinkedFrom>,
+    pub restrictions: Option<Restrictions>,
+    pub name: String,
+    pub popularity: i32,
+    #[serde(rename = "preview_url")]
+    pub preview_url: Option<String>,
+    #[serde(rename = "track_number")]
+    pub track_number: i32,
+    #[serde(rename = "type")]
+    pub type_field: String,
+    pub uri: String,
+    #[serde(rename = "is_local")]
+    pub is_local: bool,
+}
+
+/// The album of a [`PlaylistTrack`], everything but the name is null for local files
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaylistTrackAlbum {
+    #[serde(rename = "album_type")]
+    pub album_type: Option<String>,
+    #[serde(rename = "total_tracks")]
+    pub total_tracks: Option<i32>,
+    #[serde(rename = "external_urls")]
+    pub external_urls: ExternalUrls,
+    pub href: Option<String>,
+    pub id: Option<String>,
+    pub images: Vec<Image>,
+    pub name: String,
+    #[serde(rename = "release_date")]
+    pub release_date: Option<String>,
+    #[serde(rename = "release_date_precision")]
+    pub release_date_precision: Option<String>,
+    #[serde(rename = "type")]
+    pub type_field: String,
+    pub uri: Option<String>,
+    pub artists: Vec<PlaylistTrackArtist>,
+}
+
+/// An artist of a [`PlaylistTrack`], local files only have the name
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaylistTrackArtist {
+    #[serde(rename = "external_urls")]
+    pub external_urls: ExternalUrls,
+    pub href: Option<String>,
+    pub id: Option<String>,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub type_field: String,
+    pub uri: Option<String>,
+}
+
+/// What spotify sends back after a playlist's items were changed
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Snapshot {
+    #[serde(rename = "snapshot_id")]
+    pub snapshot_id: String,
+}
@@ -0,0 +1,23 @@
+use super::artist_structs::{Album, Artist, Track};
+use super::audiobook_structs::Audiobook;
+use super::paging::Paging;
+use super::playlist_structs::SimplifiedPlaylist;
+use super::show_structs::{Episode, Show};
+
+use serde::{Deserialize, Serialize};
+
+/// A page of results for each type that was searched for, types that weren't searched for are None
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchResults {
+    pub albums: Option<Paging<Album>>,
+    pub artists: Option<Paging<Artist>>,
+    /// Spotify sometimes puts nulls in with the playlists
+    pub playlists: Option<Paging<Option<SimplifiedPlaylist>>>,
+    pub tracks: Option<Paging<Track>>,
+    /// Like the playlists these can have nulls in them
+    pub shows: Option<Paging<Option<Show>>>,
+    pub episodes: Option<Paging<Option<Episode>>>,
+    /// Only in the markets audiobooks are sold in
+    pub audiobooks: Option<Paging<Option<Audiobook>>>,
+}
@@ -0,0 +1,146 @@
+use super::album_structs::Copyright;
+use super::artist_structs::{ExternalUrls, Image, Restrictions};
+use super::paging::Paging;
+
+use serde::{Deserialize, Serialize};
+
+/// Everything about a show (podcast), what the show endpoint gives back.
+///
+/// [`Show`] is the shorter version of this used inside of other objects (episodes, saved shows, etc).
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FullShow {
+    #[serde(rename = "available_markets")]
+    pub available_markets: Option<Vec<String>>,
+    pub copyrights: Vec<Copyright>,
+    pub description: String,
+    #[serde(rename = "html_description")]
+    pub html_description: Option<String>,
+    pub explicit: bool,
+    #[serde(rename = "external_urls")]
+    pub external_urls: ExternalUrls,
+    pub href: String,
+    pub id: String,
+    pub images: Vec<Image>,
+    #[serde(rename = "is_externally_hosted")]
+    pub is_externally_hosted: Option<bool>,
+    pub languages: Vec<String>,
+    #[serde(rename = "media_type")]
+    pub media_type: String,
+    pub name: String,
+    pub publisher: String,
+    #[serde(rename = "type")]
+    pub type_field: String,
+    pub uri: String,
+    #[serde(rename = "total_episodes")]
+    pub total_episodes: i32,
+    pub episodes: ShowEpisodes,
+}
+
+/// A page of the episodes of a show
+pub type ShowEpisodes = Paging<Episode>;
+
+/// A show (podcast) without its episodes
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Show {
+    #[serde(rename = "available_markets")]
+    pub available_markets: Option<Vec<String>>,
+    pub copyrights: Vec<Copyright>,
+    pub description: String,
+    #[serde(rename = "html_description")]
+    pub html_description: Option<String>,
+    pub explicit: bool,
+    #[serde(rename = "external_urls")]
+    pub external_urls: ExternalUrls,
+    pub href: String,
+    pub id: String,
+    pub images: Vec<Image>,
+    #[serde(rename = "is_externally_hosted")]
+    pub is_externally_hosted: Option<bool>,
+    /// ISO 639 codes of the languages used in the show
+    pub languages: Vec<String>,
+    #[serde(rename = "media_type")]
+    pub media_type: String,
+    pub name: String,
+    pub publisher: String,
+    #[serde(rename = "type")]
+    pub type_field: String,
+    pub uri: String,
+    #[serde(rename = "total_episodes")]
+    pub total_episodes: i32,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Episode {
+    #[serde(rename = "audio_preview_url")]
+    pub audio_preview_url: Option<String>,
+    pub description: String,
+    #[serde(rename = "html_description")]
+    pub html_description: Option<String>,
+    #[serde(rename = "duration_ms")]
+    pub duration_ms: i32,
+    pub explicit: bool,
+    #[serde(rename = "external_urls")]
+    pub external_urls: ExternalUrls,
+    pub href: String,
+    pub id: String,
+    pub images: Vec<Image>,
+    #[serde(rename = "is_externally_hosted")]
+    pub is_externally_hosted: Option<bool>,
+    #[serde(rename = "is_playable")]
+    pub is_playable: Option<bool>,
+    pub languages: Vec<String>,
+    pub name: String,
+    #[serde(rename = "release_date")]
+    pub release_date: String,
+    #[serde(rename = "release_date_precision")]
+    pub release_date_precision: ReleaseDatePrecision,
+    /// Where the user stopped listening, needs a user token with the `user-read-playback-position` scope
+    #[serde(rename = "resume_point")]
+    pub resume_point: Option<ResumePoint>,
+    pub restrictions: Option<Restrictions>,
+    #[serde(rename = "type")]
+    pub type_field: String,
+    pub uri: String,
+    /// Not there when the show is already known, like the episodes of a show
+    pub show: Option<Show>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResumePoint {
+    #[serde(rename = "fully_played")]
+    pub fully_played: bool,
+    #[serde(rename = "resume_position_ms")]
+    pub resume_position_ms: i32,
+}
+
+/// How much of a release date is known
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReleaseDatePrecision {
+    /// Only the year, the date looks like `2021`
+    Year,
+    /// The year and month, the date looks like `2021-03`
+    Month,
+    /// The full date, `2021-03-14`
+    #[default]
+    Day,
+}
+
+impl Episode {
+    /// The release date as (year, month, day), the month and day are None when they aren't known
+    pub fn release(&self) -> Option<(i32, Option<u32>, Option<u32>)> {
+        let mut parts = self.release_date.split('-');
+        let year = parts.next()?.parse().ok()?;
+        let month = parts.next().and_then(|month| month.parse().ok());
+        let day = parts.next().and_then(|day| day.parse().ok());
+        match self.release_date_precision {
+            ReleaseDatePrecision::Year => Some((year, None, None)),
+            ReleaseDatePrecision::Month => Some((year, month, None)),
+            ReleaseDatePrecision::Day => Some((year, month, day)),
+        }
+    }
+}
@@ -2,13 +2,20 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct User {
+    /// Only for the current user, needs the `user-read-private` scope
+    pub country: Option<String>,
     pub display_name: Option<String>,
+    /// Only for the current user, needs the `user-read-email` scope
+    pub email: Option<String>,
+    /// Only for the current user, needs the `user-read-private` scope
+    pub explicit_content: Option<ExplicitContent>,
     pub external_urls: Urls,
     pub followers: Follow,
     pub href: String,
     pub id: String,
-    pub images: Image,
-    pub product: String,
+    pub images: Vec<Image>,
+    /// Only for the current user, needs the `user-read-private` scope
+    pub product: Option<String>,
     #[serde(rename="type")]
     pub utype: String,
     pub uri: String,
@@ -21,8 +28,8 @@ pub struct Urls {
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Follow {
-    // Null
-    pub href: String,
+    // Always null at the moment
+    pub href: Option<String>,
     pub total: i32,
 }
 
@@ -31,4 +38,12 @@ pub struct Image {
     pub url: String,
     pub height: Option<i32>,
     pub width: Option<i32>
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExplicitContent {
+    /// If explicit content should not be played
+    pub filter_enabled: bool,
+    /// If the user can't change the setting themselves
+    pub filter_locked: bool,
 }
\ No newline at end of file
@@ -0,0 +1,75 @@
+use crate::core::{batch::get_several, endpoint::{take_request, GenerateConstructors}, http::{query, Http}};
+use crate::core::structs::show_structs::Episode;
+use crate::error::Error;
+
+use serde::{Deserialize, Serialize};
+
+// Get Several Episodes only takes 50 ids at a time
+const EPISODE_CHUNK: usize = 50;
+
+/// Gets podcast episodes.
+///
+/// Nothing is cached since with a user token the episodes come back with the user's `resume_point`, which changes as they listen.
+#[derive(Debug, Clone)]
+pub struct EpisodeData {
+    pub userid: String,
+    http: Http,
+    request: Option<EpisodeRequest>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum EpisodeResult {
+    Episode(Box<Episode>),
+    /// In the same order as the ids that were asked for, None for ids spotify doesn't know
+    Episodes(Vec<Option<Episode>>),
+}
+
+// What the next call to run will fetch
+#[derive(Debug, Clone, PartialEq)]
+enum EpisodeRequest {
+    Episode { id: String, market: Option<String> },
+    Episodes { ids: Vec<String>, market: Option<String> },
+}
+
+impl EpisodeData {
+    GenerateConstructors!(app EpisodeData);
+
+    pub(crate) fn from_http(http: Http) -> EpisodeData {
+        EpisodeData {
+            userid: http.tokens().client_id().to_owned(),
+            http,
+            request: None,
+        }
+    }
+
+    /// Gets an episode along with its show
+    pub fn get_episode(&mut self, episodeid: &str, market: Option<&str>) -> &mut Self {
+        self.request = Some(EpisodeRequest::Episode { id: episodeid.to_string(), market: market.map(str::to_string) });
+        self
+    }
+
+    /// Gets any number of episodes, they are asked for 50 at a time (all at once) and put back in the order of `ids`
+    pub fn get_episodes(&mut self, ids: &[&str], market: Option<&str>) -> &mut Self {
+        self.request = Some(EpisodeRequest::Episodes {
+            ids: ids.iter().map(|id| id.to_string()).collect(),
+            market: market.map(str::to_string),
+        });
+        self
+    }
+
+    pub async fn run(&mut self) -> Result<EpisodeResult, Error> {
+        let request = take_request(&mut self.request)?;
+
+        match request {
+            EpisodeRequest::Episode { id, market } => {
+                let url = self.http.url(&format!("/episodes/{}", id));
+                let episode: Episode = self.http.get_json(&url, &query(&[("market", market)])).await?;
+                Ok(EpisodeResult::Episode(Box::new(episode)))
+            }
+            EpisodeRequest::Episodes { ids, market } => {
+                let episodes = get_several(&self.http, "/episodes", "episodes", &ids, EPISODE_CHUNK, &query(&[("market", market)])).await?;
+                Ok(EpisodeResult::Episodes(episodes))
+            }
+        }
+    }
+}
@@ -0,0 +1,95 @@
+//! The error type used by all of rustify.
+
+use crate::core::auth::AuthError;
+
+use std::fmt;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+/// The `{"error": {"status", "message"}}` object spotify sends back with most failed requests.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApiError {
+    pub status: u16,
+    pub message: String,
+}
+
+#[derive(Debug)]
+pub enum Error {
+    /// Spotify answered with an error status, `error` holds what spotify said went wrong if it said anything
+    Api { status: u16, error: Option<ApiError> },
+    /// Still rate limited after every retry allowed by the [`RetryPolicy`](crate::core::ratelimits::RetryPolicy)
+    RateLimited { retry_after: Option<Duration> },
+    /// The response could not be read into the expected struct, `path` is where in the JSON it went wrong
+    Decode { path: String, source: serde_json::Error },
+    /// Getting or refreshing a token failed
+    Auth(AuthError),
+    /// The arguments can never make a valid request (too many ids, bad values, etc)
+    InvalidArguments(String),
+    /// The request could not be sent or the response could not be read
+    Request(reqwest::Error),
+    Io(std::io::Error),
+}
+
+impl Error {
+    /// The HTTP status spotify answered with, if it answered at all
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            Error::Api { status, .. } => Some(*status),
+            Error::RateLimited { .. } => Some(429),
+            Error::Auth(AuthError::Rejected { status, .. }) => Some(*status),
+            Error::Request(err) => err.status().map(|status| status.as_u16()),
+            _ => None,
+        }
+    }
+
+    pub fn is_not_found(&self) -> bool {
+        self.status() == Some(404)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Api { status, error: Some(error) } => write!(f, "spotify returned {}: {}", status, error.message),
+            Error::Api { status, error: None } => write!(f, "spotify returned {}", status),
+            Error::RateLimited { retry_after: Some(retry_after) } => write!(f, "rate limited, retry after {} seconds", retry_after.as_secs()),
+            Error::RateLimited { retry_after: None } => write!(f, "rate limited"),
+            Error::Decode { path, source } => write!(f, "could not decode the response at `{}`: {}", path, source),
+            Error::Auth(err) => write!(f, "{}", err),
+            Error::InvalidArguments(reason) => write!(f, "invalid arguments: {}", reason),
+            Error::Request(err) => write!(f, "request failed: {}", err),
+            Error::Io(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Decode { source, .. } => Some(source),
+            Error::Auth(err) => Some(err),
+            Error::Request(err) => Some(err),
+            Error::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<AuthError> for Error {
+    fn from(err: AuthError) -> Error {
+        Error::Auth(err)
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(err: reqwest::Error) -> Error {
+        Error::Request(err)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Error {
+        Error::Io(err)
+    }
+}
@@ -0,0 +1,214 @@
+use crate::core::{endpoint::{take_request, GenerateConstructors}, http::{query, Http}, paginator::{self, fetch_pages, paginate, PagedRequest}};
+use crate::core::structs::{artist_structs::Artist, follow_structs::FollowedArtists, paging::CursorPaging};
+use crate::error::Error;
+
+use futures::future::try_join_all;
+use futures::stream::Stream;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+// Following, unfollowing and checking artists or users takes 50 ids at a time
+const FOLLOW_CHUNK: usize = 50;
+
+// Checking who follows a playlist takes 5 user ids at a time
+const PLAYLIST_FOLLOWER_CHUNK: usize = 5;
+
+/// Who and what the current user follows.
+///
+/// Needs a user token made with [`TokenProvider::user`](crate::core::auth::TokenProvider::user) and the `user-follow-read` (to read) or `user-follow-modify` (to change) scopes,
+/// playlists use the `playlist-modify-public`/`playlist-modify-private` scopes instead.
+#[derive(Debug, Clone)]
+pub struct FollowData {
+    pub userid: String,
+    http: Http,
+    request: Option<FollowRequest>,
+}
+
+/// What can be followed with ids
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FollowType {
+    Artist,
+    User,
+}
+
+impl FollowType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FollowType::Artist => "artist",
+            FollowType::User => "user",
+        }
+    }
+}
+
+/// If the artist, user or playlist with `id` is followed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FollowStatus {
+    pub id: String,
+    pub following: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum FollowResult {
+    Artists(CursorPaging<Artist>),
+    /// One for each id that was asked about, in the same order
+    Following(Vec<FollowStatus>),
+    /// For following and unfollowing, spotify doesn't send anything back
+    Null,
+}
+
+// What the next call to run will fetch or change
+#[derive(Debug, Clone, PartialEq)]
+enum FollowRequest {
+    FollowedArtists { after: Option<String>, limit: Option<i32>, max_pages: usize },
+    Follow { kind: FollowType, ids: Vec<String> },
+    Unfollow { kind: FollowType, ids: Vec<String> },
+    Contains { kind: FollowType, ids: Vec<String> },
+    FollowPlaylist { id: String, public: Option<bool> },
+    UnfollowPlaylist { id: String },
+    PlaylistFollowers { id: String, user_ids: Vec<String> },
+}
+
+impl PagedRequest for FollowRequest {
+    fn max_pages(&mut self) -> Option<&mut usize> {
+        match self {
+            FollowRequest::FollowedArtists { max_pages, .. } => Some(max_pages),
+            _ => None,
+        }
+    }
+}
+
+impl FollowData {
+    GenerateConstructors!(user FollowData);
+
+    pub(crate) fn from_http(http: Http) -> FollowData {
+        FollowData {
+            userid: http.tokens().client_id().to_owned(),
+            http,
+            request: None,
+        }
+    }
+
+    /// Gets a page of the artists the current user follows, starting after the artist with the id `after`.
+    ///
+    /// Add [`all_pages`](FollowData::all_pages) to get the rest of the pages as well.
+    pub fn get_followed_artists(&mut self, after: Option<&str>, limit: Option<i32>) -> &mut Self {
+        self.request = Some(FollowRequest::FollowedArtists { after: after.map(str::to_string), limit, max_pages: 1 });
+        self
+    }
+
+    /// Follows any number of artists or users, they are sent 50 at a time one after the other
+    pub fn follow(&mut self, kind: FollowType, ids: &[&str]) -> &mut Self {
+        self.request = Some(FollowRequest::Follow { kind, ids: ids.iter().map(|id| id.to_string()).collect() });
+        self
+    }
+
+    /// Unfollows any number of artists or users, they are sent 50 at a time one after the other
+    pub fn unfollow(&mut self, kind: FollowType, ids: &[&str]) -> &mut Self {
+        self.request = Some(FollowRequest::Unfollow { kind, ids: ids.iter().map(|id| id.to_string()).collect() });
+        self
+    }
+
+    /// Checks if the current user follows each of any number of artists or users
+    pub fn is_following(&mut self, kind: FollowType, ids: &[&str]) -> &mut Self {
+        self.request = Some(FollowRequest::Contains { kind, ids: ids.iter().map(|id| id.to_string()).collect() });
+        self
+    }
+
+    /// Follows a playlist, `public` is if it shows up on the user's profile (true if None)
+    pub fn follow_playlist(&mut self, playlistid: &str, public: Option<bool>) -> &mut Self {
+        self.request = Some(FollowRequest::FollowPlaylist { id: playlistid.to_string(), public });
+        self
+    }
+
+    pub fn unfollow_playlist(&mut self, playlistid: &str) -> &mut Self {
+        self.request = Some(FollowRequest::UnfollowPlaylist { id: playlistid.to_string() });
+        self
+    }
+
+    /// Checks if each of the users follows the playlist
+    pub fn users_follow_playlist(&mut self, playlistid: &str, userids: &[&str]) -> &mut Self {
+        self.request = Some(FollowRequest::PlaylistFollowers {
+            id: playlistid.to_string(),
+            user_ids: userids.iter().map(|id| id.to_string()).collect(),
+        });
+        self
+    }
+
+    /// Gets up to `max_pages` pages of the followed artists, see [paging](crate::core::paginator)
+    pub fn all_pages(&mut self, max_pages: usize) -> Result<&mut Self, Error> {
+        paginator::all_pages(self.request.as_mut(), max_pages)?;
+        Ok(self)
+    }
+
+    /// A stream of every artist the current user follows, pages are only fetched when the artists in the last one have been used up.
+    pub fn followed_artist_stream(&self) -> impl Stream<Item = Result<Artist, Error>> {
+        let query = vec![("type", "artist".to_string()), ("limit", "50".to_string())];
+        paginate::<FollowedArtists>(self.http.clone(), self.http.url("/me/following"), query)
+    }
+
+    pub async fn run(&mut self) -> Result<FollowResult, Error> {
+        let request = take_request(&mut self.request)?;
+
+        match request {
+            FollowRequest::FollowedArtists { after, limit, max_pages } => {
+                let url = self.http.url("/me/following");
+                let query = query(&[("type", Some("artist".to_string())), ("after", after), ("limit", limit.map(|limit| limit.to_string()))]);
+                let artists: FollowedArtists = self.http.get_json(&url, &query).await?;
+                Ok(FollowResult::Artists(fetch_pages(&self.http, artists, max_pages).await?.artists))
+            }
+            FollowRequest::Follow { kind, ids } => {
+                self.change(Method::PUT, kind, &ids).await?;
+                Ok(FollowResult::Null)
+            }
+            FollowRequest::Unfollow { kind, ids } => {
+                self.change(Method::DELETE, kind, &ids).await?;
+                Ok(FollowResult::Null)
+            }
+            FollowRequest::Contains { kind, ids } => {
+                let url = self.http.url("/me/following/contains");
+                let following = self.check(&url, &[("type", kind.as_str().to_string())], &ids, FOLLOW_CHUNK).await?;
+                Ok(FollowResult::Following(following))
+            }
+            FollowRequest::FollowPlaylist { id, public } => {
+                let url = self.http.url(&format!("/playlists/{}/followers", id));
+                self.http.send_empty(Method::PUT, &url, &(), Some(&json!({ "public": public.unwrap_or(true) }))).await?;
+                Ok(FollowResult::Null)
+            }
+            FollowRequest::UnfollowPlaylist { id } => {
+                let url = self.http.url(&format!("/playlists/{}/followers", id));
+                self.http.send_empty::<_, ()>(Method::DELETE, &url, &(), None).await?;
+                Ok(FollowResult::Null)
+            }
+            FollowRequest::PlaylistFollowers { id, user_ids } => {
+                let url = self.http.url(&format!("/playlists/{}/followers/contains", id));
+                let following = self.check(&url, &[], &user_ids, PLAYLIST_FOLLOWER_CHUNK).await?;
+                Ok(FollowResult::Following(following))
+            }
+        }
+    }
+
+    // Follows or unfollows the ids a chunk at a time
+    async fn change(&self, method: Method, kind: FollowType, ids: &[String]) -> Result<(), Error> {
+        if ids.is_empty() {
+            return Err(Error::InvalidArguments("no ids were given".to_string()));
+        }
+        let url = self.http.url("/me/following");
+        for chunk in ids.chunks(FOLLOW_CHUNK) {
+            let query = [("type", kind.as_str().to_string()), ("ids", chunk.join(","))];
+            self.http.send_empty::<_, ()>(method.clone(), &url, &query, None).await?;
+        }
+        Ok(())
+    }
+
+    // Asks about the ids a chunk at a time (all at once) and pairs each id up with spotify's answer
+    async fn check(&self, url: &str, query: &[(&'static str, String)], ids: &[String], chunk_size: usize) -> Result<Vec<FollowStatus>, Error> {
+        let requests = ids.chunks(chunk_size).map(|chunk| {
+            let mut query = query.to_vec();
+            query.push(("ids", chunk.join(",")));
+            async move { self.http.get_json::<Vec<bool>, _>(url, &query).await }
+        });
+        let answers = try_join_all(requests).await?;
+        Ok(ids.iter().zip(answers.into_iter().flatten()).map(|(id, following)| FollowStatus { id: id.clone(), following }).collect())
+    }
+}
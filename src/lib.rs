@@ -3,5 +3,44 @@
 #[cfg(feature="core")]
 pub mod core;
 
+pub mod error;
+
+pub use error::Error;
+
+#[cfg(feature="client")]
+pub mod client;
+
+#[cfg(feature="client")]
+pub use client::SpotifyClient;
+
 pub mod artist;
 
+pub mod album;
+
+pub mod track;
+
+pub mod playlist;
+
+pub mod search;
+
+pub mod user;
+
+pub mod library;
+
+pub mod follow;
+
+pub mod player;
+
+pub mod show;
+
+pub mod episode;
+
+pub mod audiobook;
+
+pub mod chapter;
+
+pub mod browse;
+
+#[cfg(feature="blocking")]
+pub mod blocking;
+
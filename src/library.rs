@@ -0,0 +1,206 @@
+use crate::core::{endpoint::{take_request, GenerateConstructors}, http::{query, Http}, paginator::{self, fetch_pages, paginate, PagedRequest}};
+use crate::core::structs::{audiobook_structs::Audiobook, library_structs::{SavedAlbum, SavedEpisode, SavedShow, SavedTrack}, paging::Paging};
+use crate::error::Error;
+
+use futures::future::try_join_all;
+use futures::stream::Stream;
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+/// The current user's "Your Library".
+///
+/// Every request is for the user the token is for, so the client needs a user token made with
+/// [`TokenProvider::user`](crate::core::auth::TokenProvider::user) and the `user-library-read` (to read) or `user-library-modify` (to change) scopes.
+/// Nothing is cached since the library can change at any time.
+#[derive(Debug, Clone)]
+pub struct LibraryData {
+    pub userid: String,
+    http: Http,
+    request: Option<LibraryRequest>,
+}
+
+/// The collections in a user's library
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LibraryType {
+    Tracks,
+    Albums,
+    Shows,
+    Episodes,
+    Audiobooks,
+}
+
+impl LibraryType {
+    fn path(&self) -> &'static str {
+        match self {
+            LibraryType::Tracks => "/me/tracks",
+            LibraryType::Albums => "/me/albums",
+            LibraryType::Shows => "/me/shows",
+            LibraryType::Episodes => "/me/episodes",
+            LibraryType::Audiobooks => "/me/audiobooks",
+        }
+    }
+
+    // How many ids spotify takes at a time when saving, removing or checking
+    fn chunk_size(&self) -> usize {
+        match self {
+            LibraryType::Albums => 20,
+            _ => 50,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum LibraryResult {
+    Tracks(Paging<SavedTrack>),
+    Albums(Paging<SavedAlbum>),
+    Shows(Paging<SavedShow>),
+    Episodes(Paging<SavedEpisode>),
+    Audiobooks(Paging<Audiobook>),
+    /// If each id is saved, in the same order as the ids that were asked about
+    Contains(Vec<bool>),
+    /// For saving and removing, spotify doesn't send anything back
+    Null,
+}
+
+// What the next call to run will fetch or change
+#[derive(Debug, Clone, PartialEq)]
+enum LibraryRequest {
+    Saved { kind: LibraryType, market: Option<String>, limit: Option<i32>, offset: Option<i32>, max_pages: usize },
+    Save { kind: LibraryType, ids: Vec<String> },
+    Remove { kind: LibraryType, ids: Vec<String> },
+    Contains { kind: LibraryType, ids: Vec<String> },
+}
+
+impl PagedRequest for LibraryRequest {
+    fn max_pages(&mut self) -> Option<&mut usize> {
+        match self {
+            LibraryRequest::Saved { max_pages, .. } => Some(max_pages),
+            LibraryRequest::Save { .. } | LibraryRequest::Remove { .. } | LibraryRequest::Contains { .. } => None,
+        }
+    }
+}
+
+impl LibraryData {
+    GenerateConstructors!(user LibraryData);
+
+    pub(crate) fn from_http(http: Http) -> LibraryData {
+        LibraryData {
+            userid: http.tokens().client_id().to_owned(),
+            http,
+            request: None,
+        }
+    }
+
+    /// Gets a page of the saved items of `kind`, add [`all_pages`](LibraryData::all_pages) to get the rest of the pages as well.
+    ///
+    /// `market` is ignored for shows and audiobooks.
+    pub fn get_saved(&mut self, kind: LibraryType, market: Option<&str>, limit: Option<i32>, offset: Option<i32>) -> &mut Self {
+        self.request = Some(LibraryRequest::Saved { kind, market: market.map(str::to_string), limit, offset, max_pages: 1 });
+        self
+    }
+
+    /// Saves any number of ids to the library, they are sent 50 at a time (20 for albums) one after the other
+    pub fn save(&mut self, kind: LibraryType, ids: &[&str]) -> &mut Self {
+        self.request = Some(LibraryRequest::Save { kind, ids: ids.iter().map(|id| id.to_string()).collect() });
+        self
+    }
+
+    /// Removes any number of ids from the library, they are sent 50 at a time (20 for albums) one after the other
+    pub fn remove(&mut self, kind: LibraryType, ids: &[&str]) -> &mut Self {
+        self.request = Some(LibraryRequest::Remove { kind, ids: ids.iter().map(|id| id.to_string()).collect() });
+        self
+    }
+
+    /// Checks if each of any number of ids is saved, the chunks are all sent at once
+    pub fn contains(&mut self, kind: LibraryType, ids: &[&str]) -> &mut Self {
+        self.request = Some(LibraryRequest::Contains { kind, ids: ids.iter().map(|id| id.to_string()).collect() });
+        self
+    }
+
+    /// Gets up to `max_pages` pages of the saved items, see [paging](crate::core::paginator)
+    pub fn all_pages(&mut self, max_pages: usize) -> Result<&mut Self, Error> {
+        paginator::all_pages(self.request.as_mut(), max_pages)?;
+        Ok(self)
+    }
+
+    /// A stream of every saved track, pages are only fetched when the tracks in the last one have been used up.
+    pub fn saved_track_stream(&self, market: Option<&str>) -> impl Stream<Item = Result<SavedTrack, Error>> {
+        self.stream(LibraryType::Tracks, market)
+    }
+
+    pub fn saved_album_stream(&self, market: Option<&str>) -> impl Stream<Item = Result<SavedAlbum, Error>> {
+        self.stream(LibraryType::Albums, market)
+    }
+
+    pub fn saved_show_stream(&self) -> impl Stream<Item = Result<SavedShow, Error>> {
+        self.stream(LibraryType::Shows, None)
+    }
+
+    pub fn saved_episode_stream(&self, market: Option<&str>) -> impl Stream<Item = Result<SavedEpisode, Error>> {
+        self.stream(LibraryType::Episodes, market)
+    }
+
+    pub fn saved_audiobook_stream(&self) -> impl Stream<Item = Result<Audiobook, Error>> {
+        self.stream(LibraryType::Audiobooks, None)
+    }
+
+    fn stream<T: DeserializeOwned>(&self, kind: LibraryType, market: Option<&str>) -> impl Stream<Item = Result<T, Error>> {
+        let query = query(&[("market", market.map(str::to_string)), ("limit", Some("50".to_string()))]);
+        paginate::<Paging<T>>(self.http.clone(), self.http.url(kind.path()), query)
+    }
+
+    pub async fn run(&mut self) -> Result<LibraryResult, Error> {
+        let request = take_request(&mut self.request)?;
+
+        match request {
+            LibraryRequest::Saved { kind, market, limit, offset, max_pages } => {
+                let url = self.http.url(kind.path());
+                let query = query(&[("market", market), ("limit", limit.map(|limit| limit.to_string())), ("offset", offset.map(|offset| offset.to_string()))]);
+                Ok(match kind {
+                    LibraryType::Tracks => LibraryResult::Tracks(self.saved(&url, &query, max_pages).await?),
+                    LibraryType::Albums => LibraryResult::Albums(self.saved(&url, &query, max_pages).await?),
+                    LibraryType::Shows => LibraryResult::Shows(self.saved(&url, &query, max_pages).await?),
+                    LibraryType::Episodes => LibraryResult::Episodes(self.saved(&url, &query, max_pages).await?),
+                    LibraryType::Audiobooks => LibraryResult::Audiobooks(self.saved(&url, &query, max_pages).await?),
+                })
+            }
+            LibraryRequest::Save { kind, ids } => {
+                self.change(Method::PUT, kind, &ids).await?;
+                Ok(LibraryResult::Null)
+            }
+            LibraryRequest::Remove { kind, ids } => {
+                self.change(Method::DELETE, kind, &ids).await?;
+                Ok(LibraryResult::Null)
+            }
+            LibraryRequest::Contains { kind, ids } => {
+                let url = self.http.url(&format!("{}/contains", kind.path()));
+                let http = &self.http;
+                let requests = ids.chunks(kind.chunk_size()).map(|chunk| {
+                    let query = [("ids", chunk.join(","))];
+                    let url = &url;
+                    async move { http.get_json::<Vec<bool>, _>(url, &query).await }
+                });
+                let chunks = try_join_all(requests).await?;
+                Ok(LibraryResult::Contains(chunks.into_iter().flatten().collect()))
+            }
+        }
+    }
+
+    async fn saved<T: DeserializeOwned>(&self, url: &str, query: &[(&'static str, String)], max_pages: usize) -> Result<Paging<T>, Error> {
+        let page: Paging<T> = self.http.get_json(url, query).await?;
+        fetch_pages(&self.http, page, max_pages).await
+    }
+
+    // Saves or removes the ids a chunk at a time
+    async fn change(&self, method: Method, kind: LibraryType, ids: &[String]) -> Result<(), Error> {
+        if ids.is_empty() {
+            return Err(Error::InvalidArguments("no ids were given".to_string()));
+        }
+        let url = self.http.url(kind.path());
+        for chunk in ids.chunks(kind.chunk_size()) {
+            self.http.send_empty::<_, ()>(method.clone(), &url, &[("ids", chunk.join(","))], None).await?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,305 @@
+use crate::core::{endpoint::{take_request, GenerateConstructors}, http::{query, Http}, paginator::{self, fetch_pages, paginate, PagedRequest}};
+use crate::core::structs::{paging::CursorPaging, player_structs::{CurrentlyPlaying, Device, Devices, PlayHistory, PlaybackState, Queue, RepeatState}};
+use crate::error::Error;
+
+use futures::stream::Stream;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+/// Sees and controls what the current user is playing.
+///
+/// Needs a user token made with [`TokenProvider::user`](crate::core::auth::TokenProvider::user) and the `user-read-playback-state` (to read),
+/// `user-modify-playback-state` (to control) or `user-read-recently-played` scopes. Controlling playback only works
+/// for users with Spotify Premium. Nothing is cached since playback changes all of the time.
+#[derive(Debug, Clone)]
+pub struct PlayerData {
+    pub userid: String,
+    http: Http,
+    request: Option<PlayerRequest>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum PlayerResult {
+    /// None when nothing is playing and no device is active
+    State(Option<Box<PlaybackState>>),
+    /// None when nothing is playing
+    CurrentlyPlaying(Option<Box<CurrentlyPlaying>>),
+    Devices(Vec<Device>),
+    Queue(Queue),
+    RecentlyPlayed(CursorPaging<PlayHistory>),
+    /// For controlling playback, spotify doesn't send anything back
+    Null,
+}
+
+/// Where to start reading the recently played tracks from, in milliseconds since the unix epoch
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeCursor {
+    /// Tracks played before this time
+    Before(i64),
+    /// Tracks played after this time
+    After(i64),
+}
+
+/// What to play when starting playback, leaving everything as None resumes whatever was playing.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StartPlayback {
+    #[serde(rename = "context_uri", skip_serializing_if = "Option::is_none")]
+    pub context_uri: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uris: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<serde_json::Value>,
+    #[serde(rename = "position_ms", skip_serializing_if = "Option::is_none")]
+    pub position_ms: Option<i64>,
+}
+
+impl StartPlayback {
+    pub fn new() -> StartPlayback {
+        StartPlayback::default()
+    }
+
+    /// Plays an album, artist or playlist by its uri
+    pub fn context(mut self, uri: &str) -> Self {
+        self.context_uri = Some(uri.to_string());
+        self
+    }
+
+    /// Plays these tracks or episodes one after the other
+    pub fn uris(mut self, uris: &[&str]) -> Self {
+        self.uris = Some(uris.iter().map(|uri| uri.to_string()).collect());
+        self
+    }
+
+    /// Starts at the item at `position` (from 0) of the context or uris
+    pub fn offset_position(mut self, position: i32) -> Self {
+        self.offset = Some(json!({ "position": position }));
+        self
+    }
+
+    /// Starts at the item with `uri` in the context or uris
+    pub fn offset_uri(mut self, uri: &str) -> Self {
+        self.offset = Some(json!({ "uri": uri }));
+        self
+    }
+
+    /// Starts this far into the first item
+    pub fn position_ms(mut self, position_ms: i64) -> Self {
+        self.position_ms = Some(position_ms);
+        self
+    }
+}
+
+// What the next call to run will fetch or do
+#[derive(Debug, Clone, PartialEq)]
+enum PlayerRequest {
+    State { market: Option<String> },
+    CurrentlyPlaying { market: Option<String> },
+    Devices,
+    Transfer { device_id: String, play: Option<bool> },
+    Play { device_id: Option<String>, playback: StartPlayback },
+    Pause { device_id: Option<String> },
+    Seek { position_ms: i64, device_id: Option<String> },
+    Next { device_id: Option<String> },
+    Previous { device_id: Option<String> },
+    Repeat { state: RepeatState, device_id: Option<String> },
+    Shuffle { state: bool, device_id: Option<String> },
+    Volume { percent: u8, device_id: Option<String> },
+    AddToQueue { uri: String, device_id: Option<String> },
+    Queue,
+    RecentlyPlayed { limit: Option<i32>, cursor: Option<TimeCursor>, max_pages: usize },
+}
+
+impl PagedRequest for PlayerRequest {
+    fn max_pages(&mut self) -> Option<&mut usize> {
+        match self {
+            PlayerRequest::RecentlyPlayed { max_pages, .. } => Some(max_pages),
+            _ => None,
+        }
+    }
+}
+
+impl PlayerData {
+    GenerateConstructors!(user PlayerData);
+
+    pub(crate) fn from_http(http: Http) -> PlayerData {
+        PlayerData {
+            userid: http.tokens().client_id().to_owned(),
+            http,
+            request: None,
+        }
+    }
+
+    /// Gets the device, progress, repeat and shuffle state and what is playing, tracks and episodes both come back
+    pub fn get_playback_state(&mut self, market: Option<&str>) -> &mut Self {
+        self.request = Some(PlayerRequest::State { market: market.map(str::to_string) });
+        self
+    }
+
+    pub fn get_currently_playing(&mut self, market: Option<&str>) -> &mut Self {
+        self.request = Some(PlayerRequest::CurrentlyPlaying { market: market.map(str::to_string) });
+        self
+    }
+
+    pub fn get_devices(&mut self) -> &mut Self {
+        self.request = Some(PlayerRequest::Devices);
+        self
+    }
+
+    /// Moves playback to another device, `play` starts it playing there (if None it keeps its current state)
+    pub fn transfer_playback(&mut self, device_id: &str, play: Option<bool>) -> &mut Self {
+        self.request = Some(PlayerRequest::Transfer { device_id: device_id.to_string(), play });
+        self
+    }
+
+    /// Starts or resumes playback, on the active device if `device_id` is None
+    pub fn play(&mut self, device_id: Option<&str>, playback: StartPlayback) -> &mut Self {
+        self.request = Some(PlayerRequest::Play { device_id: device_id.map(str::to_string), playback });
+        self
+    }
+
+    pub fn pause(&mut self, device_id: Option<&str>) -> &mut Self {
+        self.request = Some(PlayerRequest::Pause { device_id: device_id.map(str::to_string) });
+        self
+    }
+
+    /// Jumps to `position_ms` into the current item, past the end of it skips to the next one
+    pub fn seek(&mut self, position_ms: i64, device_id: Option<&str>) -> &mut Self {
+        self.request = Some(PlayerRequest::Seek { position_ms, device_id: device_id.map(str::to_string) });
+        self
+    }
+
+    pub fn next(&mut self, device_id: Option<&str>) -> &mut Self {
+        self.request = Some(PlayerRequest::Next { device_id: device_id.map(str::to_string) });
+        self
+    }
+
+    pub fn previous(&mut self, device_id: Option<&str>) -> &mut Self {
+        self.request = Some(PlayerRequest::Previous { device_id: device_id.map(str::to_string) });
+        self
+    }
+
+    pub fn repeat(&mut self, state: RepeatState, device_id: Option<&str>) -> &mut Self {
+        self.request = Some(PlayerRequest::Repeat { state, device_id: device_id.map(str::to_string) });
+        self
+    }
+
+    pub fn shuffle(&mut self, state: bool, device_id: Option<&str>) -> &mut Self {
+        self.request = Some(PlayerRequest::Shuffle { state, device_id: device_id.map(str::to_string) });
+        self
+    }
+
+    /// Sets the volume from 0 to 100
+    pub fn volume(&mut self, percent: u8, device_id: Option<&str>) -> &mut Self {
+        self.request = Some(PlayerRequest::Volume { percent, device_id: device_id.map(str::to_string) });
+        self
+    }
+
+    /// Adds a track or episode to the end of the queue
+    pub fn add_to_queue(&mut self, uri: &str, device_id: Option<&str>) -> &mut Self {
+        self.request = Some(PlayerRequest::AddToQueue { uri: uri.to_string(), device_id: device_id.map(str::to_string) });
+        self
+    }
+
+    pub fn get_queue(&mut self) -> &mut Self {
+        self.request = Some(PlayerRequest::Queue);
+        self
+    }
+
+    /// Gets a page of the tracks the user played recently (podcast episodes aren't included).
+    ///
+    /// Add [`all_pages`](PlayerData::all_pages) to keep going back through the history.
+    pub fn get_recently_played(&mut self, limit: Option<i32>, cursor: Option<TimeCursor>) -> &mut Self {
+        self.request = Some(PlayerRequest::RecentlyPlayed { limit, cursor, max_pages: 1 });
+        self
+    }
+
+    /// Gets up to `max_pages` pages of the recently played tracks, see [paging](crate::core::paginator)
+    pub fn all_pages(&mut self, max_pages: usize) -> Result<&mut Self, Error> {
+        paginator::all_pages(self.request.as_mut(), max_pages)?;
+        Ok(self)
+    }
+
+    /// A stream of the recently played tracks going back in time, pages are only fetched when the tracks in the last one have been used up.
+    pub fn recently_played_stream(&self) -> impl Stream<Item = Result<PlayHistory, Error>> {
+        paginate::<CursorPaging<PlayHistory>>(self.http.clone(), self.http.url("/me/player/recently-played"), vec![("limit", "50".to_string())])
+    }
+
+    pub async fn run(&mut self) -> Result<PlayerResult, Error> {
+        let request = take_request(&mut self.request)?;
+
+        match request {
+            PlayerRequest::State { market } => {
+                let query = query(&[("market", market), ("additional_types", Some("track,episode".to_string()))]);
+                let state: Option<PlaybackState> = self.http.get_optional_json(&self.http.url("/me/player"), &query).await?;
+                Ok(PlayerResult::State(state.map(Box::new)))
+            }
+            PlayerRequest::CurrentlyPlaying { market } => {
+                let query = query(&[("market", market), ("additional_types", Some("track,episode".to_string()))]);
+                let playing: Option<CurrentlyPlaying> = self.http.get_optional_json(&self.http.url("/me/player/currently-playing"), &query).await?;
+                Ok(PlayerResult::CurrentlyPlaying(playing.map(Box::new)))
+            }
+            PlayerRequest::Devices => {
+                let devices: Devices = self.http.get_json(&self.http.url("/me/player/devices"), &[] as &[(&str, &str)]).await?;
+                Ok(PlayerResult::Devices(devices.devices))
+            }
+            PlayerRequest::Transfer { device_id, play } => {
+                let mut body = json!({ "device_ids": [device_id] });
+                if let Some(play) = play {
+                    body["play"] = json!(play);
+                }
+                self.http.send_empty(Method::PUT, &self.http.url("/me/player"), &(), Some(&body)).await?;
+                Ok(PlayerResult::Null)
+            }
+            PlayerRequest::Play { device_id, playback } => {
+                if playback.context_uri.is_some() && playback.uris.is_some() {
+                    return Err(Error::InvalidArguments("only one of a context or uris can be played".to_string()));
+                }
+                let url = self.http.url("/me/player/play");
+                self.http.send_empty(Method::PUT, &url, &query(&[("device_id", device_id)]), Some(&playback)).await?;
+                Ok(PlayerResult::Null)
+            }
+            PlayerRequest::Pause { device_id } => self.control(Method::PUT, "/me/player/pause", &[("device_id", device_id)]).await,
+            PlayerRequest::Seek { position_ms, device_id } => {
+                self.control(Method::PUT, "/me/player/seek", &[("position_ms", Some(position_ms.to_string())), ("device_id", device_id)]).await
+            }
+            PlayerRequest::Next { device_id } => self.control(Method::POST, "/me/player/next", &[("device_id", device_id)]).await,
+            PlayerRequest::Previous { device_id } => self.control(Method::POST, "/me/player/previous", &[("device_id", device_id)]).await,
+            PlayerRequest::Repeat { state, device_id } => {
+                self.control(Method::PUT, "/me/player/repeat", &[("state", Some(state.as_str().to_string())), ("device_id", device_id)]).await
+            }
+            PlayerRequest::Shuffle { state, device_id } => {
+                self.control(Method::PUT, "/me/player/shuffle", &[("state", Some(state.to_string())), ("device_id", device_id)]).await
+            }
+            PlayerRequest::Volume { percent, device_id } => {
+                if percent > 100 {
+                    return Err(Error::InvalidArguments(format!("the volume has to be from 0 to 100, not {}", percent)));
+                }
+                self.control(Method::PUT, "/me/player/volume", &[("volume_percent", Some(percent.to_string())), ("device_id", device_id)]).await
+            }
+            PlayerRequest::AddToQueue { uri, device_id } => {
+                self.control(Method::POST, "/me/player/queue", &[("uri", Some(uri)), ("device_id", device_id)]).await
+            }
+            PlayerRequest::Queue => {
+                let queue: Queue = self.http.get_json(&self.http.url("/me/player/queue"), &[] as &[(&str, &str)]).await?;
+                Ok(PlayerResult::Queue(queue))
+            }
+            PlayerRequest::RecentlyPlayed { limit, cursor, max_pages } => {
+                let (before, after) = match cursor {
+                    Some(TimeCursor::Before(before)) => (Some(before.to_string()), None),
+                    Some(TimeCursor::After(after)) => (None, Some(after.to_string())),
+                    None => (None, None),
+                };
+                let query = query(&[("limit", limit.map(|limit| limit.to_string())), ("before", before), ("after", after)]);
+                let history: CursorPaging<PlayHistory> = self.http.get_json(&self.http.url("/me/player/recently-played"), &query).await?;
+                Ok(PlayerResult::RecentlyPlayed(fetch_pages(&self.http, history, max_pages).await?))
+            }
+        }
+    }
+
+    // Sends a playback command, they all have their arguments in the query and nothing in the body
+    async fn control(&self, method: Method, path: &str, params: &[(&'static str, Option<String>)]) -> Result<PlayerResult, Error> {
+        self.http.send_empty::<_, ()>(method, &self.http.url(path), &query(params), None).await?;
+        Ok(PlayerResult::Null)
+    }
+}
@@ -0,0 +1,316 @@
+use crate::core::{endpoint::{take_request, GenerateConstructors}, http::{query, Http}, paginator::{self, fetch_pages, paginate, PagedRequest}};
+use crate::core::structs::{paging::Paging, playlist_structs::{Playlist, PlaylistItem, PlaylistItems, SimplifiedPlaylist, Snapshot}};
+use crate::error::Error;
+
+use futures::stream::Stream;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+// Adding and removing items only takes 100 uris at a time
+const ITEM_CHUNK: usize = 100;
+
+/// Reads and edits playlists.
+///
+/// Everything other than reading public playlists needs a user token, made with [`TokenProvider::user`](crate::core::auth::TokenProvider::user) and the
+/// `playlist-modify-public`/`playlist-modify-private` (or `playlist-read-private` to read) scopes.
+/// Nothing is cached since playlists can change at any time.
+#[derive(Debug, Clone)]
+pub struct PlaylistData {
+    pub userid: String,
+    http: Http,
+    request: Option<PlaylistRequest>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum PlaylistResult {
+    Playlist(Box<Playlist>),
+    Items(PlaylistItems),
+    Playlists(Paging<SimplifiedPlaylist>),
+    /// The snapshot id of the playlist after the change, pass it to the next change to make sure nothing else changed it in between
+    Snapshot(String),
+    /// For changes spotify doesn't send anything back for
+    Null,
+}
+
+/// The details of a playlist to set when creating it or changing it, anything left as None is left as it is.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlaylistDetails {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public: Option<bool>,
+    /// Only private playlists can be collaborative
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collaborative: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl PlaylistDetails {
+    pub fn new() -> PlaylistDetails {
+        PlaylistDetails::default()
+    }
+
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    pub fn public(mut self, public: bool) -> Self {
+        self.public = Some(public);
+        self
+    }
+
+    pub fn collaborative(mut self, collaborative: bool) -> Self {
+        self.collaborative = Some(collaborative);
+        self
+    }
+
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+}
+
+// What the next call to run will fetch or change
+#[derive(Debug, Clone, PartialEq)]
+enum PlaylistRequest {
+    Playlist { id: String, market: Option<String> },
+    Items { id: String, market: Option<String>, limit: Option<i32>, offset: Option<i32>, max_pages: usize },
+    Create { user_id: String, details: PlaylistDetails },
+    ChangeDetails { id: String, details: PlaylistDetails },
+    Add { id: String, uris: Vec<String>, position: Option<i32> },
+    Remove { id: String, uris: Vec<String>, snapshot_id: Option<String> },
+    Reorder { id: String, range_start: i32, insert_before: i32, range_length: Option<i32>, snapshot_id: Option<String> },
+    Replace { id: String, uris: Vec<String> },
+    CurrentUserPlaylists { limit: Option<i32>, offset: Option<i32>, max_pages: usize },
+    UserPlaylists { user_id: String, limit: Option<i32>, offset: Option<i32>, max_pages: usize },
+}
+
+impl PagedRequest for PlaylistRequest {
+    fn max_pages(&mut self) -> Option<&mut usize> {
+        match self {
+            PlaylistRequest::Items { max_pages, .. } | PlaylistRequest::CurrentUserPlaylists { max_pages, .. } | PlaylistRequest::UserPlaylists { max_pages, .. } => Some(max_pages),
+            _ => None,
+        }
+    }
+}
+
+impl PlaylistData {
+    GenerateConstructors!(app PlaylistData);
+
+    pub(crate) fn from_http(http: Http) -> PlaylistData {
+        PlaylistData {
+            userid: http.tokens().client_id().to_owned(),
+            http,
+            request: None,
+        }
+    }
+
+    /// Gets a playlist with the first page of its items
+    pub fn get_playlist(&mut self, playlistid: &str, market: Option<&str>) -> &mut Self {
+        self.request = Some(PlaylistRequest::Playlist { id: playlistid.to_string(), market: market.map(str::to_string) });
+        self
+    }
+
+    /// Gets a page of the playlist's items, add [`all_pages`](PlaylistData::all_pages) to get the rest of the pages as well
+    pub fn get_items(&mut self, playlistid: &str, market: Option<&str>, limit: Option<i32>, offset: Option<i32>) -> &mut Self {
+        self.request = Some(PlaylistRequest::Items { id: playlistid.to_string(), market: market.map(str::to_string), limit, offset, max_pages: 1 });
+        self
+    }
+
+    /// Creates a playlist for `userid` (which has to be the user the token is for), `details` needs a name
+    pub fn create_playlist(&mut self, userid: &str, details: PlaylistDetails) -> &mut Self {
+        self.request = Some(PlaylistRequest::Create { user_id: userid.to_string(), details });
+        self
+    }
+
+    pub fn change_details(&mut self, playlistid: &str, details: PlaylistDetails) -> &mut Self {
+        self.request = Some(PlaylistRequest::ChangeDetails { id: playlistid.to_string(), details });
+        self
+    }
+
+    /// Adds tracks or episodes by their uri, at `position` or the end if it is None.
+    ///
+    /// Any number of uris can be given, they are added 100 at a time one after the other so they keep their order.
+    pub fn add_items(&mut self, playlistid: &str, uris: &[&str], position: Option<i32>) -> &mut Self {
+        self.request = Some(PlaylistRequest::Add {
+            id: playlistid.to_string(),
+            uris: uris.iter().map(|uri| uri.to_string()).collect(),
+            position,
+        });
+        self
+    }
+
+    /// Removes every occurrence of the uris, 100 at a time.
+    ///
+    /// If `snapshot_id` is given spotify makes the first change against that version of the playlist,
+    /// every change after that is made against the snapshot the one before it returned.
+    pub fn remove_items(&mut self, playlistid: &str, uris: &[&str], snapshot_id: Option<&str>) -> &mut Self {
+        self.request = Some(PlaylistRequest::Remove {
+            id: playlistid.to_string(),
+            uris: uris.iter().map(|uri| uri.to_string()).collect(),
+            snapshot_id: snapshot_id.map(str::to_string),
+        });
+        self
+    }
+
+    /// Moves `range_length` items (1 if None) starting at `range_start` to before the item at `insert_before`
+    pub fn reorder_items(&mut self, playlistid: &str, range_start: i32, insert_before: i32, range_length: Option<i32>, snapshot_id: Option<&str>) -> &mut Self {
+        self.request = Some(PlaylistRequest::Reorder {
+            id: playlistid.to_string(),
+            range_start,
+            insert_before,
+            range_length,
+            snapshot_id: snapshot_id.map(str::to_string),
+        });
+        self
+    }
+
+    /// Replaces every item in the playlist with `uris`, an empty slice clears the playlist.
+    ///
+    /// Spotify only replaces 100 at a time, anything past that is added on afterwards.
+    pub fn replace_items(&mut self, playlistid: &str, uris: &[&str]) -> &mut Self {
+        self.request = Some(PlaylistRequest::Replace {
+            id: playlistid.to_string(),
+            uris: uris.iter().map(|uri| uri.to_string()).collect(),
+        });
+        self
+    }
+
+    /// Gets a page of the playlists the current user owns or follows, needs a user token
+    pub fn get_current_user_playlists(&mut self, limit: Option<i32>, offset: Option<i32>) -> &mut Self {
+        self.request = Some(PlaylistRequest::CurrentUserPlaylists { limit, offset, max_pages: 1 });
+        self
+    }
+
+    /// Gets a page of a user's public playlists
+    pub fn get_user_playlists(&mut self, userid: &str, limit: Option<i32>, offset: Option<i32>) -> &mut Self {
+        self.request = Some(PlaylistRequest::UserPlaylists { user_id: userid.to_string(), limit, offset, max_pages: 1 });
+        self
+    }
+
+    /// Gets up to `max_pages` pages of the playlist's items or of a user's playlists, see [paging](crate::core::paginator)
+    pub fn all_pages(&mut self, max_pages: usize) -> Result<&mut Self, Error> {
+        paginator::all_pages(self.request.as_mut(), max_pages)?;
+        Ok(self)
+    }
+
+    /// A stream of every item in the playlist, pages are only fetched when the items in the last one have been used up.
+    pub fn item_stream(&self, playlistid: &str, market: Option<&str>) -> impl Stream<Item = Result<PlaylistItem, Error>> {
+        let query = query(&[("market", market.map(str::to_string)), ("limit", Some("100".to_string()))]);
+        paginate::<PlaylistItems>(self.http.clone(), self.http.url(&format!("/playlists/{}/tracks", playlistid)), query)
+    }
+
+    /// A stream of every playlist the current user owns or follows
+    pub fn current_user_playlist_stream(&self) -> impl Stream<Item = Result<SimplifiedPlaylist, Error>> {
+        paginate::<Paging<SimplifiedPlaylist>>(self.http.clone(), self.http.url("/me/playlists"), vec![("limit", "50".to_string())])
+    }
+
+    pub async fn run(&mut self) -> Result<PlaylistResult, Error> {
+        let request = take_request(&mut self.request)?;
+
+        match request {
+            PlaylistRequest::Playlist { id, market } => {
+                let url = self.http.url(&format!("/playlists/{}", id));
+                let playlist: Playlist = self.http.get_json(&url, &query(&[("market", market)])).await?;
+                Ok(PlaylistResult::Playlist(Box::new(playlist)))
+            }
+            PlaylistRequest::Items { id, market, limit, offset, max_pages } => {
+                let url = self.http.url(&format!("/playlists/{}/tracks", id));
+                let query = query(&[("market", market), ("limit", limit.map(|limit| limit.to_string())), ("offset", offset.map(|offset| offset.to_string()))]);
+                let items: PlaylistItems = self.http.get_json(&url, &query).await?;
+                Ok(PlaylistResult::Items(fetch_pages(&self.http, items, max_pages).await?))
+            }
+            PlaylistRequest::Create { user_id, details } => {
+                if details.name.is_none() {
+                    return Err(Error::InvalidArguments("a playlist needs a name to be created".to_string()));
+                }
+                let url = self.http.url(&format!("/users/{}/playlists", user_id));
+                let playlist: Playlist = self.http.send_json(Method::POST, &url, &(), Some(&details)).await?;
+                Ok(PlaylistResult::Playlist(Box::new(playlist)))
+            }
+            PlaylistRequest::ChangeDetails { id, details } => {
+                let url = self.http.url(&format!("/playlists/{}", id));
+                self.http.send_empty(Method::PUT, &url, &(), Some(&details)).await?;
+                Ok(PlaylistResult::Null)
+            }
+            PlaylistRequest::Add { id, uris, position } => {
+                if uris.is_empty() {
+                    return Err(Error::InvalidArguments("no uris were given to add".to_string()));
+                }
+                Ok(PlaylistResult::Snapshot(self.add(&id, &uris, position).await?))
+            }
+            PlaylistRequest::Remove { id, uris, mut snapshot_id } => {
+                if uris.is_empty() {
+                    return Err(Error::InvalidArguments("no uris were given to remove".to_string()));
+                }
+                let url = self.http.url(&format!("/playlists/{}/tracks", id));
+                for chunk in uris.chunks(ITEM_CHUNK) {
+                    let tracks: Vec<_> = chunk.iter().map(|uri| json!({ "uri": uri })).collect();
+                    let mut body = json!({ "tracks": tracks });
+                    if let Some(snapshot_id) = &snapshot_id {
+                        body["snapshot_id"] = json!(snapshot_id);
+                    }
+                    let snapshot: Snapshot = self.http.send_json(Method::DELETE, &url, &(), Some(&body)).await?;
+                    snapshot_id = Some(snapshot.snapshot_id);
+                }
+                Ok(PlaylistResult::Snapshot(snapshot_id.unwrap_or_default()))
+            }
+            PlaylistRequest::Reorder { id, range_start, insert_before, range_length, snapshot_id } => {
+                let url = self.http.url(&format!("/playlists/{}/tracks", id));
+                let mut body = json!({
+                    "range_start": range_start,
+                    "insert_before": insert_before,
+                    "range_length": range_length.unwrap_or(1),
+                });
+                if let Some(snapshot_id) = &snapshot_id {
+                    body["snapshot_id"] = json!(snapshot_id);
+                }
+                let snapshot: Snapshot = self.http.send_json(Method::PUT, &url, &(), Some(&body)).await?;
+                Ok(PlaylistResult::Snapshot(snapshot.snapshot_id))
+            }
+            PlaylistRequest::Replace { id, uris } => {
+                let url = self.http.url(&format!("/playlists/{}/tracks", id));
+                let (first, rest) = uris.split_at(uris.len().min(ITEM_CHUNK));
+                let snapshot: Snapshot = self.http.send_json(Method::PUT, &url, &(), Some(&json!({ "uris": first }))).await?;
+                if rest.is_empty() {
+                    return Ok(PlaylistResult::Snapshot(snapshot.snapshot_id));
+                }
+                Ok(PlaylistResult::Snapshot(self.add(&id, rest, None).await?))
+            }
+            PlaylistRequest::CurrentUserPlaylists { limit, offset, max_pages } => {
+                let url = self.http.url("/me/playlists");
+                self.playlists(&url, limit, offset, max_pages).await
+            }
+            PlaylistRequest::UserPlaylists { user_id, limit, offset, max_pages } => {
+                let url = self.http.url(&format!("/users/{}/playlists", user_id));
+                self.playlists(&url, limit, offset, max_pages).await
+            }
+        }
+    }
+
+    // Adds the uris a chunk at a time, returning the snapshot after the last chunk
+    async fn add(&self, id: &str, uris: &[String], mut position: Option<i32>) -> Result<String, Error> {
+        let url = self.http.url(&format!("/playlists/{}/tracks", id));
+        let mut snapshot_id = String::new();
+        for chunk in uris.chunks(ITEM_CHUNK) {
+            let mut body = json!({ "uris": chunk });
+            if let Some(position) = position {
+                body["position"] = json!(position);
+            }
+            let snapshot: Snapshot = self.http.send_json(Method::POST, &url, &(), Some(&body)).await?;
+            snapshot_id = snapshot.snapshot_id;
+            // The next chunk goes straight after this one
+            position = position.map(|position| position + chunk.len() as i32);
+        }
+        Ok(snapshot_id)
+    }
+
+    async fn playlists(&self, url: &str, limit: Option<i32>, offset: Option<i32>, max_pages: usize) -> Result<PlaylistResult, Error> {
+        let query = query(&[("limit", limit.map(|limit| limit.to_string())), ("offset", offset.map(|offset| offset.to_string()))]);
+        let playlists: Paging<SimplifiedPlaylist> = self.http.get_json(url, &query).await?;
+        Ok(PlaylistResult::Playlists(fetch_pages(&self.http, playlists, max_pages).await?))
+    }
+}
@@ -0,0 +1,218 @@
+use crate::core::{cache::Cache, endpoint::{take_request, GenerateConstructors}, http::{query, Http}};
+use crate::core::structs::search_structs::SearchResults;
+use crate::error::Error;
+
+use std::fmt;
+
+#[derive(Debug, Clone)]
+pub struct SearchData {
+    pub userid: String,
+    cache: Cache<SearchResults>,
+    http: Http,
+    request: Option<SearchQuery>,
+}
+
+/// The kinds of things that can be searched for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SearchType {
+    Album,
+    Artist,
+    Playlist,
+    Track,
+    Show,
+    Episode,
+    Audiobook,
+}
+
+impl SearchType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SearchType::Album => "album",
+            SearchType::Artist => "artist",
+            SearchType::Playlist => "playlist",
+            SearchType::Track => "track",
+            SearchType::Show => "show",
+            SearchType::Episode => "episode",
+            SearchType::Audiobook => "audiobook",
+        }
+    }
+}
+
+/// Everything about a search: the keywords, field filters, which types to look for and where.
+///
+/// ```
+/// use rustify::search::{SearchQuery, SearchType};
+///
+/// let query = SearchQuery::new("remaster")
+///     .artist("Miles Davis")
+///     .years(1955, 1960)
+///     .types(&[SearchType::Album, SearchType::Track])
+///     .market("US");
+/// assert_eq!(query.to_string(), r#"remaster artist:"Miles Davis" year:1955-1960"#);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SearchQuery {
+    keywords: String,
+    filters: Vec<String>,
+    types: Vec<SearchType>,
+    market: Option<String>,
+    limit: Option<i32>,
+    offset: Option<i32>,
+    include_external: bool,
+}
+
+impl SearchQuery {
+    /// A search for `keywords`, which can be empty if the filters are enough
+    pub fn new(keywords: &str) -> SearchQuery {
+        SearchQuery {
+            keywords: keywords.to_string(),
+            ..SearchQuery::default()
+        }
+    }
+
+    fn filter(mut self, field: &str, value: &str) -> Self {
+        // Values with spaces in them have to be quoted or only the first word is used for the filter
+        if value.contains(char::is_whitespace) {
+            self.filters.push(format!("{}:\"{}\"", field, value));
+        } else {
+            self.filters.push(format!("{}:{}", field, value));
+        }
+        self
+    }
+
+    pub fn artist(self, artist: &str) -> Self {
+        self.filter("artist", artist)
+    }
+
+    pub fn album(self, album: &str) -> Self {
+        self.filter("album", album)
+    }
+
+    pub fn track(self, track: &str) -> Self {
+        self.filter("track", track)
+    }
+
+    pub fn year(self, year: u16) -> Self {
+        self.filter("year", &year.to_string())
+    }
+
+    /// Released between `from` and `to`, both included
+    pub fn years(self, from: u16, to: u16) -> Self {
+        self.filter("year", &format!("{}-{}", from, to))
+    }
+
+    pub fn genre(self, genre: &str) -> Self {
+        self.filter("genre", genre)
+    }
+
+    /// Tracks with this International Standard Recording Code
+    pub fn isrc(self, isrc: &str) -> Self {
+        self.filter("isrc", isrc)
+    }
+
+    /// Albums with this Universal Product Code
+    pub fn upc(self, upc: &str) -> Self {
+        self.filter("upc", upc)
+    }
+
+    /// Only albums released in the past two weeks
+    pub fn tag_new(self) -> Self {
+        self.filter("tag", "new")
+    }
+
+    /// Only albums in the lowest 10% of popularity
+    pub fn tag_hipster(self) -> Self {
+        self.filter("tag", "hipster")
+    }
+
+    /// The types of results to get, at least one is needed
+    pub fn types(mut self, types: &[SearchType]) -> Self {
+        for search_type in types {
+            if !self.types.contains(search_type) {
+                self.types.push(*search_type);
+            }
+        }
+        self
+    }
+
+    /// Only results available in `market`, an ISO 3166-1 alpha-2 country code
+    pub fn market(mut self, market: &str) -> Self {
+        self.market = Some(market.to_string());
+        self
+    }
+
+    /// How many results of each type to get, up to 50
+    pub fn limit(mut self, limit: i32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Where to start in the results of each type, up to 1000
+    pub fn offset(mut self, offset: i32) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Also search externally hosted audio content, marking it as playable, this only changes the shows and episodes found
+    pub fn include_external(mut self, include_external: bool) -> Self {
+        self.include_external = include_external;
+        self
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        let types: Vec<&str> = self.types.iter().map(SearchType::as_str).collect();
+        query(&[
+            ("q", Some(self.to_string())),
+            ("type", Some(types.join(","))),
+            ("market", self.market.clone()),
+            ("limit", self.limit.map(|limit| limit.to_string())),
+            ("offset", self.offset.map(|offset| offset.to_string())),
+            ("include_external", self.include_external.then(|| "audio".to_string())),
+        ])
+    }
+}
+
+// The `q` that is sent to spotify
+impl fmt::Display for SearchQuery {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let parts: Vec<&str> = std::iter::once(self.keywords.trim()).chain(self.filters.iter().map(String::as_str)).filter(|part| !part.is_empty()).collect();
+        write!(f, "{}", parts.join(" "))
+    }
+}
+
+impl SearchData {
+    GenerateConstructors!(app SearchData, Cache);
+
+    pub(crate) fn from_http(http: Http, cache: Cache<SearchResults>) -> SearchData {
+        SearchData {
+            userid: http.tokens().client_id().to_owned(),
+            cache,
+            http,
+            request: None,
+        }
+    }
+
+    pub fn search(&mut self, query: SearchQuery) -> &mut Self {
+        self.request = Some(query);
+        self
+    }
+
+    pub async fn run(&mut self) -> Result<SearchResults, Error> {
+        let query = take_request(&mut self.request)?;
+        if query.types.is_empty() {
+            return Err(Error::InvalidArguments("a search needs at least one type to look for".to_string()));
+        }
+        if query.keywords.trim().is_empty() && query.filters.is_empty() {
+            return Err(Error::InvalidArguments("a search needs keywords or a filter".to_string()));
+        }
+
+        let params = query.params();
+        let key = format!("{:?}", params);
+        if let Some(value) = self.cache.get(&key) {
+            return Ok(value);
+        }
+        let results: SearchResults = self.http.get_json(&self.http.url("/search"), &params).await?;
+        self.cache.add(&key, results.clone());
+        Ok(results)
+    }
+}
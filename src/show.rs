@@ -0,0 +1,114 @@
+use crate::core::{batch::get_several, endpoint::{take_request, GenerateConstructors}, http::{query, Http}, paginator::{self, fetch_pages, paginate, PagedRequest}};
+use crate::core::structs::show_structs::{Episode, FullShow, Show, ShowEpisodes};
+use crate::error::Error;
+
+use futures::stream::Stream;
+use serde::{Deserialize, Serialize};
+
+// Get Several Shows only takes 50 ids at a time
+const SHOW_CHUNK: usize = 50;
+
+/// Gets podcast shows.
+///
+/// Shows aren't cached, with a user token the episodes that come with a show have the user's `resume_point` in them
+/// and that keeps changing. See [`EpisodeData`](crate::episode::EpisodeData) for the same thing with single episodes.
+#[derive(Debug, Clone)]
+pub struct ShowData {
+    pub userid: String,
+    http: Http,
+    request: Option<ShowRequest>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ShowResult {
+    Show(Box<FullShow>),
+    /// In the same order as the ids that were asked for, None for ids spotify doesn't know
+    Shows(Vec<Option<Show>>),
+    Episodes(ShowEpisodes),
+}
+
+// What the next call to run will fetch
+#[derive(Debug, Clone, PartialEq)]
+enum ShowRequest {
+    Show { id: String, market: Option<String> },
+    Shows { ids: Vec<String>, market: Option<String> },
+    Episodes { id: String, market: Option<String>, limit: Option<i32>, offset: Option<i32>, max_pages: usize },
+}
+
+impl PagedRequest for ShowRequest {
+    fn max_pages(&mut self) -> Option<&mut usize> {
+        match self {
+            ShowRequest::Episodes { max_pages, .. } => Some(max_pages),
+            ShowRequest::Show { .. } | ShowRequest::Shows { .. } => None,
+        }
+    }
+}
+
+impl ShowData {
+    GenerateConstructors!(app ShowData);
+
+    pub(crate) fn from_http(http: Http) -> ShowData {
+        ShowData {
+            userid: http.tokens().client_id().to_owned(),
+            http,
+            request: None,
+        }
+    }
+
+    /// Gets a show with the first page of its episodes.
+    ///
+    /// With an app token spotify only knows shows that are available in `market`, so it should usually be given.
+    pub fn get_show(&mut self, showid: &str, market: Option<&str>) -> &mut Self {
+        self.request = Some(ShowRequest::Show { id: showid.to_string(), market: market.map(str::to_string) });
+        self
+    }
+
+    /// Gets any number of shows, they are asked for 50 at a time (all at once) and put back in the order of `ids`
+    pub fn get_shows(&mut self, ids: &[&str], market: Option<&str>) -> &mut Self {
+        self.request = Some(ShowRequest::Shows {
+            ids: ids.iter().map(|id| id.to_string()).collect(),
+            market: market.map(str::to_string),
+        });
+        self
+    }
+
+    /// Gets a page of the show's episodes, add [`all_pages`](ShowData::all_pages) to get the rest of the pages as well
+    pub fn get_episodes(&mut self, showid: &str, market: Option<&str>, limit: Option<i32>, offset: Option<i32>) -> &mut Self {
+        self.request = Some(ShowRequest::Episodes { id: showid.to_string(), market: market.map(str::to_string), limit, offset, max_pages: 1 });
+        self
+    }
+
+    /// Gets up to `max_pages` pages of the show's episodes, see [paging](crate::core::paginator)
+    pub fn all_pages(&mut self, max_pages: usize) -> Result<&mut Self, Error> {
+        paginator::all_pages(self.request.as_mut(), max_pages)?;
+        Ok(self)
+    }
+
+    /// A stream of every episode of the show, pages are only fetched when the episodes in the last one have been used up.
+    pub fn episode_stream(&self, showid: &str, market: Option<&str>) -> impl Stream<Item = Result<Episode, Error>> {
+        let query = query(&[("market", market.map(str::to_string)), ("limit", Some("50".to_string()))]);
+        paginate::<ShowEpisodes>(self.http.clone(), self.http.url(&format!("/shows/{}/episodes", showid)), query)
+    }
+
+    pub async fn run(&mut self) -> Result<ShowResult, Error> {
+        let request = take_request(&mut self.request)?;
+
+        match request {
+            ShowRequest::Show { id, market } => {
+                let url = self.http.url(&format!("/shows/{}", id));
+                let show: FullShow = self.http.get_json(&url, &query(&[("market", market)])).await?;
+                Ok(ShowResult::Show(Box::new(show)))
+            }
+            ShowRequest::Shows { ids, market } => {
+                let shows = get_several(&self.http, "/shows", "shows", &ids, SHOW_CHUNK, &query(&[("market", market)])).await?;
+                Ok(ShowResult::Shows(shows))
+            }
+            ShowRequest::Episodes { id, market, limit, offset, max_pages } => {
+                let url = self.http.url(&format!("/shows/{}/episodes", id));
+                let query = query(&[("market", market), ("limit", limit.map(|limit| limit.to_string())), ("offset", offset.map(|offset| offset.to_string()))]);
+                let episodes: ShowEpisodes = self.http.get_json(&url, &query).await?;
+                Ok(ShowResult::Episodes(fetch_pages(&self.http, episodes, max_pages).await?))
+            }
+        }
+    }
+}
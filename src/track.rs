@@ -0,0 +1,310 @@
+use crate::core::{batch::get_several, cache::Cache, endpoint::{take_request, GenerateConstructors}, http::{query, Http}};
+use crate::core::structs::{artist_structs::Track, audio_structs::{AudioAnalysis, AudioFeatures, Recommendations}};
+use crate::error::Error;
+
+use serde::{Deserialize, Serialize};
+
+// Get Several Tracks only takes 50 ids at a time
+const TRACK_CHUNK: usize = 50;
+
+// Get Several Tracks' Audio Features takes 100
+const AUDIO_FEATURES_CHUNK: usize = 100;
+
+// Artists, genres and tracks together
+const MAX_SEEDS: usize = 5;
+
+#[derive(Debug, Clone)]
+pub struct TrackData {
+    pub userid: String,
+    cache: Cache<TrackResult>,
+    http: Http,
+    request: Option<TrackRequest>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum TrackResult {
+    Track(Box<Track>),
+    /// In the same order as the ids that were asked for, None for ids spotify doesn't know
+    Tracks(Vec<Option<Track>>),
+    AudioFeatures(Box<AudioFeatures>),
+    /// In the same order as the ids that were asked for, None for ids spotify has no features for
+    SeveralAudioFeatures(Vec<Option<AudioFeatures>>),
+    AudioAnalysis(Box<AudioAnalysis>),
+    Recommendations(Recommendations),
+}
+
+// What the next call to run will fetch
+#[derive(Debug, Clone, PartialEq)]
+enum TrackRequest {
+    Track { id: String, market: Option<String> },
+    Tracks { ids: Vec<String>, market: Option<String> },
+    AudioFeatures { id: String },
+    SeveralAudioFeatures { ids: Vec<String> },
+    AudioAnalysis { id: String },
+    Recommendations { query: RecommendationsQuery },
+}
+
+/// The attributes recommendations can be tuned with, see [`RecommendationsQuery`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TunableAttribute {
+    Acousticness,
+    Danceability,
+    DurationMs,
+    Energy,
+    Instrumentalness,
+    Key,
+    Liveness,
+    Loudness,
+    Mode,
+    Popularity,
+    Speechiness,
+    Tempo,
+    TimeSignature,
+    Valence,
+}
+
+impl TunableAttribute {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TunableAttribute::Acousticness => "acousticness",
+            TunableAttribute::Danceability => "danceability",
+            TunableAttribute::DurationMs => "duration_ms",
+            TunableAttribute::Energy => "energy",
+            TunableAttribute::Instrumentalness => "instrumentalness",
+            TunableAttribute::Key => "key",
+            TunableAttribute::Liveness => "liveness",
+            TunableAttribute::Loudness => "loudness",
+            TunableAttribute::Mode => "mode",
+            TunableAttribute::Popularity => "popularity",
+            TunableAttribute::Speechiness => "speechiness",
+            TunableAttribute::Tempo => "tempo",
+            TunableAttribute::TimeSignature => "time_signature",
+            TunableAttribute::Valence => "valence",
+        }
+    }
+}
+
+/// The seeds and tuning for a recommendations request.
+///
+/// Up to 5 seeds can be given, any mix of artists, genres and tracks, and at least one is needed.
+/// Every [`TunableAttribute`] can be given a `min_`, `max_` and `target_` value, the values use the same
+/// units as [`AudioFeatures`] (popularity goes from 0 to 100).
+///
+/// ```
+/// use rustify::track::{RecommendationsQuery, TunableAttribute};
+///
+/// let query = RecommendationsQuery::new()
+///     .seed_genres(&["house"])
+///     .seed_tracks(&["0c6xIDDpzE81m2q797ordA"])
+///     .min(TunableAttribute::Tempo, 120.0)
+///     .target(TunableAttribute::Energy, 0.8)
+///     .limit(50);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RecommendationsQuery {
+    seed_artists: Vec<String>,
+    seed_genres: Vec<String>,
+    seed_tracks: Vec<String>,
+    limit: Option<i32>,
+    market: Option<String>,
+    tuning: Vec<(String, f64)>,
+}
+
+impl RecommendationsQuery {
+    pub fn new() -> RecommendationsQuery {
+        RecommendationsQuery::default()
+    }
+
+    pub fn seed_artists(mut self, ids: &[&str]) -> Self {
+        self.seed_artists.extend(ids.iter().map(|id| id.to_string()));
+        self
+    }
+
+    /// Genres from [`get_genre_seeds`](crate::browse::BrowseData::get_genre_seeds)
+    pub fn seed_genres(mut self, genres: &[&str]) -> Self {
+        self.seed_genres.extend(genres.iter().map(|genre| genre.to_string()));
+        self
+    }
+
+    pub fn seed_tracks(mut self, ids: &[&str]) -> Self {
+        self.seed_tracks.extend(ids.iter().map(|id| id.to_string()));
+        self
+    }
+
+    /// How many tracks to get, from 1 to 100 (20 by default)
+    pub fn limit(mut self, limit: i32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn market(mut self, market: &str) -> Self {
+        self.market = Some(market.to_string());
+        self
+    }
+
+    /// Only tracks with at least this much of `attribute`
+    pub fn min(self, attribute: TunableAttribute, value: f64) -> Self {
+        self.tune("min", attribute, value)
+    }
+
+    /// Only tracks with at most this much of `attribute`
+    pub fn max(self, attribute: TunableAttribute, value: f64) -> Self {
+        self.tune("max", attribute, value)
+    }
+
+    /// Tracks closest to this much of `attribute` come first
+    pub fn target(self, attribute: TunableAttribute, value: f64) -> Self {
+        self.tune("target", attribute, value)
+    }
+
+    // Setting the same thing twice keeps the last value
+    fn tune(mut self, prefix: &str, attribute: TunableAttribute, value: f64) -> Self {
+        let key = format!("{}_{}", prefix, attribute.as_str());
+        self.tuning.retain(|(existing, _)| *existing != key);
+        self.tuning.push((key, value));
+        self
+    }
+
+    // Checks the query can work and builds it, so nothing is sent that spotify would only turn down
+    fn params(&self) -> Result<Vec<(String, String)>, Error> {
+        let seeds = self.seed_artists.len() + self.seed_genres.len() + self.seed_tracks.len();
+        if seeds == 0 || seeds > MAX_SEEDS {
+            return Err(Error::InvalidArguments(format!("recommendations need from 1 to {} seeds, {} were given", MAX_SEEDS, seeds)));
+        }
+        if let Some(limit) = self.limit {
+            if !(1..=100).contains(&limit) {
+                return Err(Error::InvalidArguments(format!("the limit has to be from 1 to 100, not {}", limit)));
+            }
+        }
+        for (key, min) in self.tuning.iter().filter(|(key, _)| key.starts_with("min_")) {
+            let max_key = key.replacen("min_", "max_", 1);
+            if let Some((_, max)) = self.tuning.iter().find(|(key, _)| *key == max_key) {
+                if min > max {
+                    return Err(Error::InvalidArguments(format!("{} is more than {}", key, max_key)));
+                }
+            }
+        }
+
+        let mut params = Vec::new();
+        for (key, seeds) in [("seed_artists", &self.seed_artists), ("seed_genres", &self.seed_genres), ("seed_tracks", &self.seed_tracks)] {
+            if !seeds.is_empty() {
+                params.push((key.to_string(), seeds.join(",")));
+            }
+        }
+        if let Some(limit) = self.limit {
+            params.push(("limit".to_string(), limit.to_string()));
+        }
+        if let Some(market) = &self.market {
+            params.push(("market".to_string(), market.clone()));
+        }
+        params.extend(self.tuning.iter().map(|(key, value)| (key.clone(), value.to_string())));
+        Ok(params)
+    }
+}
+
+impl Track {
+    /// The id that was asked for. When a market is given spotify can swap a track that isn't available there
+    /// for one that is (track relinking), `id` is then the new track and this is the original.
+    pub fn requested_id(&self) -> &str {
+        self.linked_from.as_ref().and_then(|linked| linked.id.as_deref()).unwrap_or(&self.id)
+    }
+}
+
+impl TrackData {
+    GenerateConstructors!(app TrackData, Cache);
+
+    pub(crate) fn from_http(http: Http, cache: Cache<TrackResult>) -> TrackData {
+        TrackData {
+            userid: http.tokens().client_id().to_owned(),
+            cache,
+            http,
+            request: None,
+        }
+    }
+
+    /// Gets a track, if `market` is given the track may be relinked to a version that is playable there
+    pub fn get_track(&mut self, trackid: &str, market: Option<&str>) -> &mut Self {
+        self.request = Some(TrackRequest::Track { id: trackid.to_string(), market: market.map(str::to_string) });
+        self
+    }
+
+    /// Gets any number of tracks, they are asked for 50 at a time (all at once) and put back in the order of `ids`
+    pub fn get_tracks(&mut self, ids: &[&str], market: Option<&str>) -> &mut Self {
+        self.request = Some(TrackRequest::Tracks {
+            ids: ids.iter().map(|id| id.to_string()).collect(),
+            market: market.map(str::to_string),
+        });
+        self
+    }
+
+    /// Gets what a track sounds like (tempo, energy, key, ...)
+    pub fn get_audio_features(&mut self, trackid: &str) -> &mut Self {
+        self.request = Some(TrackRequest::AudioFeatures { id: trackid.to_string() });
+        self
+    }
+
+    /// Gets the audio features of any number of tracks, they are asked for 100 at a time (all at once) and put back in the order of `ids`
+    pub fn get_several_audio_features(&mut self, ids: &[&str]) -> &mut Self {
+        self.request = Some(TrackRequest::SeveralAudioFeatures { ids: ids.iter().map(|id| id.to_string()).collect() });
+        self
+    }
+
+    /// Gets the bars, beats, sections, segments and tatums of a track
+    pub fn get_audio_analysis(&mut self, trackid: &str) -> &mut Self {
+        self.request = Some(TrackRequest::AudioAnalysis { id: trackid.to_string() });
+        self
+    }
+
+    /// Gets tracks like the seeds in `query`, the query is checked when [`run`](TrackData::run) is called
+    pub fn get_recommendations(&mut self, query: RecommendationsQuery) -> &mut Self {
+        self.request = Some(TrackRequest::Recommendations { query });
+        self
+    }
+
+    pub async fn run(&mut self) -> Result<TrackResult, Error> {
+        let request = take_request(&mut self.request)?;
+
+        match request {
+            TrackRequest::Track { id, market } => {
+                let key = format!("{}{}", id, market.as_deref().unwrap_or_default());
+                if let Some(value) = self.cache.get(&key) {
+                    return Ok(value);
+                }
+                let url = self.http.url(&format!("/tracks/{}", id));
+                let track: Track = self.http.get_json(&url, &query(&[("market", market)])).await?;
+                let value = TrackResult::Track(Box::new(track));
+                self.cache.add(&key, value.clone());
+                Ok(value)
+            }
+            TrackRequest::Tracks { ids, market } => {
+                let tracks = get_several(&self.http, "/tracks", "tracks", &ids, TRACK_CHUNK, &query(&[("market", market)])).await?;
+                Ok(TrackResult::Tracks(tracks))
+            }
+            TrackRequest::AudioFeatures { id } => {
+                let key = format!("features{}", id);
+                if let Some(value) = self.cache.get(&key) {
+                    return Ok(value);
+                }
+                let url = self.http.url(&format!("/audio-features/{}", id));
+                let features: AudioFeatures = self.http.get_json(&url, &[] as &[(&str, &str)]).await?;
+                let value = TrackResult::AudioFeatures(Box::new(features));
+                self.cache.add(&key, value.clone());
+                Ok(value)
+            }
+            TrackRequest::SeveralAudioFeatures { ids } => {
+                let features = get_several(&self.http, "/audio-features", "audio_features", &ids, AUDIO_FEATURES_CHUNK, &[]).await?;
+                Ok(TrackResult::SeveralAudioFeatures(features))
+            }
+            TrackRequest::AudioAnalysis { id } => {
+                let url = self.http.url(&format!("/audio-analysis/{}", id));
+                let analysis: AudioAnalysis = self.http.get_json(&url, &[] as &[(&str, &str)]).await?;
+                Ok(TrackResult::AudioAnalysis(Box::new(analysis)))
+            }
+            TrackRequest::Recommendations { query } => {
+                let params = query.params()?;
+                let recommendations: Recommendations = self.http.get_json(&self.http.url("/recommendations"), &params).await?;
+                Ok(TrackResult::Recommendations(recommendations))
+            }
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use crate::core::{cache::Cache, endpoint::{take_request, GenerateConstructors}, http::Http};
+use crate::core::structs::user_struct::User;
+use crate::error::Error;
+
+#[derive(Debug, Clone)]
+pub struct UserData {
+    pub userid: String,
+    cache: Cache<User>,
+    http: Http,
+    request: Option<UserRequest>,
+}
+
+// What the next call to run will fetch
+#[derive(Debug, Clone, PartialEq)]
+enum UserRequest {
+    CurrentUser,
+    User { id: String },
+}
+
+impl UserData {
+    GenerateConstructors!(app UserData, Cache);
+
+    pub(crate) fn from_http(http: Http, cache: Cache<User>) -> UserData {
+        UserData {
+            userid: http.tokens().client_id().to_owned(),
+            cache,
+            http,
+            request: None,
+        }
+    }
+
+    /// Gets the profile of the user the token is for, so it needs a user token.
+    ///
+    /// `country`, `product` and `explicit_content` are only filled in with the `user-read-private` scope and `email` with `user-read-email`.
+    pub fn get_current_user(&mut self) -> &mut Self {
+        self.request = Some(UserRequest::CurrentUser);
+        self
+    }
+
+    /// Gets the public profile of any user
+    pub fn get_user(&mut self, userid: &str) -> &mut Self {
+        self.request = Some(UserRequest::User { id: userid.to_string() });
+        self
+    }
+
+    pub async fn run(&mut self) -> Result<User, Error> {
+        let request = take_request(&mut self.request)?;
+
+        // Cached by path, the token (and so who `/me` is) doesn't change for a client
+        let path = match &request {
+            UserRequest::CurrentUser => "/me".to_string(),
+            UserRequest::User { id } => format!("/users/{}", id),
+        };
+        if let Some(value) = self.cache.get(&path) {
+            return Ok(value);
+        }
+        let user: User = self.http.get_json(&self.http.url(&path), &[] as &[(&str, &str)]).await?;
+        self.cache.add(&path, user.clone());
+        Ok(user)
+    }
+}
@@ -0,0 +1,69 @@
+mod common;
+
+use common::{client_for, Reply};
+
+use rustify::album::{AlbumData, AlbumResult};
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+fn album(id: &str) -> String {
+    format!(r#"{{"album_type": "album", "total_tracks": 1, "available_markets": ["US"], "external_urls": {{"spotify": ""}}, "href": "", "id": "{id}",
+        "images": [], "name": "Album {id}", "release_date": "2020-01-01", "release_date_precision": "day", "type": "album", "uri": "", "artists": [],
+        "tracks": {{"href": "", "limit": 50, "next": null, "offset": 0, "previous": null, "total": 0, "items": []}},
+        "copyrights": [{{"text": "(C) 2020", "type": "C"}}], "external_ids": {{"upc": "123"}}, "genres": [], "label": "Label", "popularity": 10}}"#)
+}
+
+client_for!(AlbumData);
+
+#[tokio::test]
+async fn get_album() {
+    let mut client = client(|request| {
+        assert!(request.path.starts_with("/albums/one"));
+        Reply::json(200, &album("one"))
+    }).await;
+
+    match client.get_album("one", Some("US")).run().await.unwrap() {
+        AlbumResult::Album(album) => {
+            assert_eq!(album.label, "Label");
+            assert_eq!(album.copyrights[0].type_field, "C");
+            assert_eq!(album.external_ids.upc.as_deref(), Some("123"));
+        }
+        other => panic!("Expected an album, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn get_albums_in_chunks() {
+    let requests = Arc::new(AtomicUsize::new(0));
+    let counter = requests.clone();
+    let mut client = client(move |request| {
+        counter.fetch_add(1, Ordering::SeqCst);
+        let ids = request.query("ids").unwrap();
+        let ids: Vec<&str> = ids.split(',').collect();
+        assert!(ids.len() <= 20);
+        let albums: Vec<String> = ids.iter().map(|id| if *id == "missing" { "null".to_string() } else { album(id) }).collect();
+        Reply::json(200, &format!(r#"{{"albums": [{}]}}"#, albums.join(",")))
+    }).await;
+
+    let mut ids: Vec<String> = (0..25).map(|number| format!("album{}", number)).collect();
+    ids[21] = "missing".to_string();
+    let ids: Vec<&str> = ids.iter().map(String::as_str).collect();
+
+    match client.get_albums(&ids, None).run().await.unwrap() {
+        AlbumResult::Albums(albums) => {
+            assert_eq!(albums.len(), 25);
+            assert!(albums[21].is_none());
+            assert_eq!(albums[24].as_ref().unwrap().id, "album24");
+            assert_eq!(albums[3].as_ref().unwrap().id, "album3");
+        }
+        other => panic!("Expected albums, got {:?}", other),
+    }
+    assert_eq!(requests.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn run_needs_a_request() {
+    let mut client = client(|_| Reply::json(500, "")).await;
+    assert!(client.run().await.is_err());
+}
@@ -0,0 +1,172 @@
+mod common;
+
+use common::{client_for, Reply, Request};
+
+use rustify::artist::{ArtistData, ArtistRequest, ArtistResult};
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+
+fn artist(id: &str) -> String {
+    format!(r#"{{"external_urls": {{"spotify": ""}}, "href": "", "id": "{id}", "name": "Artist {id}", "type": "artist", "uri": ""}}"#)
+}
+
+const EMPTY_PAGE: &str = r#"{"href": "", "limit": 20, "next": null, "offset": 0, "previous": null, "total": 0, "items": []}"#;
+
+// Answers every artist endpoint, the path says which one was asked for
+fn artists(request: Request) -> Reply {
+    let path = request.path.split('?').next().unwrap().trim_start_matches("/artists");
+    if path.is_empty() {
+        let ids = request.query("ids").unwrap();
+        let ids: Vec<&str> = ids.split(',').collect();
+        assert!(ids.len() <= 50);
+        let artists: Vec<String> = ids.into_iter().map(|id| if id.starts_with("missing") { "null".to_string() } else { artist(id) }).collect();
+        return Reply::json(200, &format!(r#"{{"artists": [{}]}}"#, artists.join(",")));
+    }
+    if path.ends_with("/albums") {
+        // Nothing from the top tracks request before this one should be sent along
+        assert_eq!(request.query("market"), None);
+        return Reply::json(200, EMPTY_PAGE);
+    }
+    if path.ends_with("/top-tracks") {
+        assert_eq!(request.query("market").as_deref(), Some("US"));
+        return Reply::json(200, r#"{"tracks": []}"#);
+    }
+    Reply::json(200, &artist(path.trim_start_matches('/')))
+}
+
+client_for!(ArtistData);
+
+#[tokio::test]
+async fn requests_dont_leak_into_each_other() {
+    let mut client = client(artists).await;
+
+    let top_tracks = client.get_top_tracks("one", "US").run().await.unwrap();
+    assert_eq!(top_tracks, ArtistResult::TopTracks(Default::default()));
+    match client.get_albums("one", None, None, None, None).run().await.unwrap() {
+        ArtistResult::Albums(albums) => assert!(albums.items.is_empty()),
+        other => panic!("Expected albums, got {:?}", other),
+    }
+    assert!(client.run().await.is_err());
+}
+
+#[tokio::test]
+async fn several_artists_fill_the_cache() {
+    let requests = Arc::new(AtomicUsize::new(0));
+    let counter = requests.clone();
+    let mut client = client(move |request| {
+        counter.fetch_add(1, Ordering::SeqCst);
+        artists(request)
+    }).await;
+
+    assert_eq!(client.get_artists(&["one", "two"]).run().await.unwrap().names(), Some(vec![Some("Artist one".to_string()), Some("Artist two".to_string())]));
+    assert_eq!(client.get_artist("two").run().await.unwrap().name(), Some("Artist two".to_string()));
+    assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+    // Both are cached so spotify isn't asked again
+    client.get_artists(&["two", "one"]).run().await.unwrap();
+    assert_eq!(requests.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn get_artists_in_chunks() {
+    let requests = Arc::new(AtomicUsize::new(0));
+    let counter = requests.clone();
+    let mut client = client(move |request| {
+        counter.fetch_add(1, Ordering::SeqCst);
+        artists(request)
+    }).await;
+    client.get_artist("artist3").run().await.unwrap();
+
+    let mut ids: Vec<String> = (0..120).map(|number| format!("artist{}", number)).collect();
+    ids[60] = "missing".to_string();
+    ids[119] = "artist0".to_string();
+    let ids: Vec<&str> = ids.iter().map(String::as_str).collect();
+
+    match client.get_artists(&ids).run().await.unwrap() {
+        ArtistResult::Artists(artists) => {
+            assert_eq!(artists.len(), 120);
+            for (id, artist) in ids.iter().zip(&artists) {
+                match artist {
+                    Some(artist) => assert_eq!(artist.id, *id),
+                    None => assert_eq!(*id, "missing"),
+                }
+            }
+        }
+        other => panic!("Expected artists, got {:?}", other),
+    }
+    // artist3 came from the cache and artist0 is only asked for once, leaving 117 ids for 3 chunks
+    assert_eq!(requests.load(Ordering::SeqCst), 1 + 3);
+}
+
+#[tokio::test]
+async fn execute_runs_requests_at_the_same_time() {
+    let client = client(artists).await;
+
+    let (artist, top_tracks, albums) = futures::join!(
+        client.execute(ArtistRequest::artist("one")),
+        client.execute(ArtistRequest::top_tracks("one", "US")),
+        client.execute(ArtistRequest::albums("one", Some(vec!["single"]), None, Some(5), None)),
+    );
+    assert_eq!(artist.unwrap().id(), Some("one".to_string()));
+    assert!(matches!(top_tracks.unwrap(), ArtistResult::TopTracks(_)));
+    assert!(matches!(albums.unwrap(), ArtistResult::Albums(_)));
+}
+
+// Holds up the stand-in's thread for a bit so the requests overlap, remembering the most that were in at once
+#[tokio::test(flavor = "multi_thread", worker_threads = 8)]
+async fn execute_all_keeps_to_the_limit_and_the_order() {
+    let in_flight = Arc::new(AtomicUsize::new(0));
+    let most = Arc::new(AtomicUsize::new(0));
+    let (counter, highest) = (in_flight.clone(), most.clone());
+    let client = client(move |request| {
+        let now = counter.fetch_add(1, Ordering::SeqCst) + 1;
+        highest.fetch_max(now, Ordering::SeqCst);
+        sleep(Duration::from_millis(50));
+        counter.fetch_sub(1, Ordering::SeqCst);
+        if request.path == "/artists/missing" {
+            return Reply::json(404, r#"{"error": {"status": 404, "message": "Non existing id"}}"#);
+        }
+        artists(request)
+    }).await;
+
+    let ids = ["a", "b", "missing", "c", "d", "e"];
+    let results = client.execute_all(ids.iter().map(|id| ArtistRequest::artist(id)), 2).await;
+    assert_eq!(results.len(), ids.len());
+    for (id, result) in ids.iter().zip(results) {
+        match result {
+            Ok(result) => assert_eq!(result.id().as_deref(), Some(*id)),
+            Err(err) => assert_eq!(*id, "missing", "{:?}", err),
+        }
+    }
+    assert!(most.load(Ordering::SeqCst) <= 2);
+    assert_eq!(in_flight.load(Ordering::SeqCst), 0);
+}
+
+// Each request waits (for up to a second) until another one is in at the same time, so only overlapping requests are quick
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn execute_all_sends_requests_together() {
+    let in_flight = Arc::new(AtomicUsize::new(0));
+    let overlapped = Arc::new(AtomicUsize::new(0));
+    let (counter, together) = (in_flight.clone(), overlapped.clone());
+    let client = client(move |request| {
+        counter.fetch_add(1, Ordering::SeqCst);
+        let start = Instant::now();
+        while counter.load(Ordering::SeqCst) < 2 && start.elapsed() < Duration::from_secs(1) {
+            sleep(Duration::from_millis(5));
+        }
+        if counter.load(Ordering::SeqCst) >= 2 {
+            together.fetch_add(1, Ordering::SeqCst);
+        }
+        // Stays counted for a little longer so the other request sees it too
+        sleep(Duration::from_millis(20));
+        counter.fetch_sub(1, Ordering::SeqCst);
+        artists(request)
+    }).await;
+
+    let results = client.execute_all([ArtistRequest::artist("a"), ArtistRequest::artist("b")], 2).await;
+    assert!(results.iter().all(Result::is_ok));
+    assert_eq!(overlapped.load(Ordering::SeqCst), 2);
+}
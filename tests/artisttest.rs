@@ -1,4 +1,4 @@
-use rustify::artist::ArtistData;
+use rustify::blocking::ArtistData;
 
 use dotenv::from_path;
 
@@ -15,15 +15,15 @@ fn all() {
 
     let start = Instant::now();
 
-    let mut result = ArtistData::new(id, secret);
+    let mut result = ArtistData::new(id, secret).unwrap();
 
     //Single artists:
     //println!("{:?}", result.get_artist("0s1ec6aPpRZ4DCj15w1EFg").run());
     //println!("{:?}", result.get_artist("0C0XlULifJtAgn6ZNCW2eu").run());
-    println!("{:?}", result.get_artist("0s1ec6aPpRZ4DCj15w1EFg").run().name());
+    println!("{:?}", result.get_artist("0s1ec6aPpRZ4DCj15w1EFg").run().unwrap().name().unwrap());
 
     //2+ artists:
-    println!("{:?}", result.get_artists(&["0s1ec6aPpRZ4DCj15w1EFg", "0C0XlULifJtAgn6ZNCW2eu"]).run().names());
+    println!("{:?}", result.get_artists(&["0s1ec6aPpRZ4DCj15w1EFg", "0C0XlULifJtAgn6ZNCW2eu"]).run().unwrap().names().unwrap());
 
     //Albums
     //println!("{:?}", result.get_albums("0C0XlULifJtAgn6ZNCW2eu", None, None, Some(1), None).run());
@@ -0,0 +1,85 @@
+mod common;
+
+use common::{client_for, Reply};
+
+use rustify::audiobook::{AudiobookData, AudiobookResult};
+
+use futures::StreamExt;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+client_for!(AudiobookData);
+
+fn chapter(number: i32) -> String {
+    format!(r#"{{"audio_preview_url": null, "chapter_number": {number}, "description": "", "duration_ms": 60000, "explicit": false,
+        "external_urls": {{"spotify": ""}}, "href": "", "id": "chapter{number}", "images": [], "is_playable": false, "languages": ["en"],
+        "name": "Chapter {number}", "release_date": "2020", "release_date_precision": "year", "restrictions": {{"reason": "payment_required"}},
+        "type": "chapter", "uri": ""}}"#)
+}
+
+#[tokio::test]
+async fn get_audiobook() {
+    let mut client = client(|request| {
+        assert!(request.path.starts_with("/audiobooks/book?"));
+        assert_eq!(request.query("market").as_deref(), Some("GB"));
+        Reply::json(200, &format!(r#"{{"authors": [{{"name": "An Author"}}], "available_markets": ["GB"], "copyrights": [], "description": "",
+            "edition": "Unabridged", "explicit": false, "external_urls": {{"spotify": ""}}, "href": "", "id": "book", "images": [],
+            "languages": ["en"], "media_type": "audio", "name": "Book", "narrators": [{{"name": "A Narrator"}}], "publisher": "Publisher",
+            "type": "audiobook", "uri": "", "total_chapters": 1,
+            "chapters": {{"href": "", "limit": 50, "next": null, "offset": 0, "previous": null, "total": 1, "items": [{}]}}}}"#, chapter(1)))
+    }).await;
+
+    match client.get_audiobook("book", Some("GB")).run().await.unwrap() {
+        AudiobookResult::Audiobook(book) => {
+            assert_eq!(book.authors[0].name, "An Author");
+            assert_eq!(book.narrators[0].name, "A Narrator");
+            assert_eq!(book.edition.as_deref(), Some("Unabridged"));
+            let chapter = &book.chapters.items[0];
+            assert_eq!(chapter.chapter_number, 1);
+            assert_eq!(chapter.restrictions.as_ref().unwrap().reason, "payment_required");
+        }
+        other => panic!("Expected an audiobook, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn audiobooks_are_not_cached() {
+    // Each request finds the user further into the first chapter
+    let listened = AtomicUsize::new(0);
+    let mut client = client(move |_| {
+        let position = listened.fetch_add(1, Ordering::SeqCst) * 1000;
+        let chapter = chapter(1).replace(r#""type": "chapter""#, &format!(r#""resume_point": {{"fully_played": false, "resume_position_ms": {position}}}, "type": "chapter""#));
+        Reply::json(200, &format!(r#"{{"authors": [], "available_markets": [], "copyrights": [], "description": "", "explicit": false,
+            "external_urls": {{"spotify": ""}}, "href": "", "id": "book", "images": [], "languages": ["en"], "media_type": "audio", "name": "Book",
+            "narrators": [], "publisher": "Publisher", "type": "audiobook", "uri": "", "total_chapters": 1,
+            "chapters": {{"href": "", "limit": 50, "next": null, "offset": 0, "previous": null, "total": 1, "items": [{}]}}}}"#, chapter))
+    }).await;
+
+    for position in [0, 1000] {
+        match client.get_audiobook("book", Some("US")).run().await.unwrap() {
+            AudiobookResult::Audiobook(book) => assert_eq!(book.chapters.items[0].resume_point.as_ref().unwrap().resume_position_ms, position),
+            other => panic!("Expected an audiobook, got {:?}", other),
+        }
+    }
+}
+
+#[tokio::test]
+async fn any_market_is_sent() {
+    let mut client = client(|request| {
+        // The market goes to spotify as it was given, spotify decides what isn't available there
+        assert_eq!(request.query("market").as_deref(), Some("us"));
+        if request.path.starts_with("/audiobooks/book/chapters") {
+            Reply::json(200, &format!(r#"{{"href": "", "limit": 50, "next": null, "offset": 0, "previous": null, "total": 1, "items": [{}]}}"#, chapter(1)))
+        } else {
+            Reply::json(200, r#"{"audiobooks": [null]}"#)
+        }
+    }).await;
+
+    match client.get_audiobooks(&["book"], Some("us")).run().await.unwrap() {
+        AudiobookResult::Audiobooks(books) => assert!(books[0].is_none()),
+        other => panic!("Expected audiobooks, got {:?}", other),
+    }
+    let chapters: Vec<_> = client.chapter_stream("book", Some("us")).collect().await;
+    assert_eq!(chapters.len(), 1);
+    assert_eq!(chapters[0].as_ref().unwrap().is_playable, Some(false));
+}
@@ -1,4 +1,4 @@
-use rustify::core::auth::get_token;
+use rustify::blocking::get_token;
 
 use dotenv::from_path;
 
@@ -13,7 +13,7 @@ fn err() {
     let id: String = var("USER_ID").unwrap();
     let secret: String = var("USER_SECRET").unwrap();
 
-    let x = get_token(id.trim_matches('1').to_string(), secret);
+    let x = get_token(id.trim_matches('1').to_string(), secret).unwrap();
     println!("{:?}", x);
 }
 
@@ -25,6 +25,6 @@ fn work() {
     let id: String = var("USER_ID").unwrap();
     let secret: String = var("USER_SECRET").unwrap();
 
-    let x = get_token(id, secret);
+    let x = get_token(id, secret).unwrap();
     println!("{:?}", x);
 }
\ No newline at end of file
@@ -0,0 +1,59 @@
+mod common;
+
+use common::{client_for, Reply};
+
+use rustify::browse::{BrowseData, BrowseResult};
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+client_for!(BrowseData);
+
+fn playlist(id: &str) -> String {
+    format!(r#"{{"collaborative": false, "description": "", "external_urls": {{"spotify": ""}}, "href": "", "id": "{id}", "images": [], "name": "Playlist {id}",
+        "owner": {{"display_name": "Spotify", "external_urls": {{"spotify": ""}}, "href": "", "id": "spotify", "type": "user", "uri": ""}},
+        "public": true, "snapshot_id": "snapshot", "tracks": {{"href": "", "total": 10}}, "type": "playlist", "uri": ""}}"#)
+}
+
+#[tokio::test]
+async fn featured_playlists_every_page() {
+    let mut client = client(|request| {
+        assert!(request.path.starts_with("/browse/featured-playlists"));
+        let host = request.header("host").unwrap();
+        let (id, next) = match request.query("offset").as_deref() {
+            None => {
+                assert_eq!(request.query("locale").as_deref(), Some("sv_SE"));
+                assert_eq!(request.query("timestamp").as_deref(), Some("2014-10-23T09:00:00"));
+                ("one", format!(r#""http://{}/browse/featured-playlists?offset=1&limit=1""#, host))
+            }
+            Some("1") => ("two", "null".to_string()),
+            Some(offset) => panic!("Unexpected offset {}", offset),
+        };
+        Reply::json(200, &format!(r#"{{"message": "Good morning", "playlists": {{"href": "", "limit": 1, "next": {next}, "offset": 0, "previous": null,
+            "total": 2, "items": [{}]}}}}"#, playlist(id)))
+    }).await;
+
+    match client.get_featured_playlists(Some("SE"), Some("sv_SE"), Some("2014-10-23T09:00:00"), Some(1), None).all_pages(10).unwrap().run().await.unwrap() {
+        BrowseResult::Playlists(playlists) => {
+            assert_eq!(playlists.message.as_deref(), Some("Good morning"));
+            let ids: Vec<&str> = playlists.playlists.items.iter().map(|playlist| playlist.id.as_str()).collect();
+            assert_eq!(ids, vec!["one", "two"]);
+        }
+        other => panic!("Expected playlists, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn markets_are_cached() {
+    let requests = Arc::new(AtomicUsize::new(0));
+    let counter = requests.clone();
+    let mut client = client(move |request| {
+        counter.fetch_add(1, Ordering::SeqCst);
+        assert_eq!(request.path, "/markets");
+        Reply::json(200, r#"{"markets": ["NZ", "SE", "US"]}"#)
+    }).await;
+
+    assert_eq!(client.get_markets().run().await.unwrap(), BrowseResult::Markets(vec!["NZ".to_string(), "SE".to_string(), "US".to_string()]));
+    client.get_markets().run().await.unwrap();
+    assert_eq!(requests.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,31 @@
+mod common;
+
+use common::{api, Reply};
+
+use rustify::chapter::{ChapterData, ChapterResult};
+use rustify::core::ratelimits::RateLimiter;
+
+#[tokio::test]
+async fn get_chapter_with_audiobook_and_resume_point() {
+    let (url, tokens) = api(|request| {
+        assert!(request.path.starts_with("/chapters/chapter"));
+        Reply::json(200, r#"{"audio_preview_url": null, "available_markets": ["US"], "chapter_number": 3, "description": "", "duration_ms": 60000,
+            "explicit": false, "external_urls": {"spotify": ""}, "href": "", "id": "chapter", "images": [], "is_playable": true, "languages": ["en"],
+            "name": "Chapter", "release_date": "2020-05", "release_date_precision": "month",
+            "resume_point": {"fully_played": false, "resume_position_ms": 2500}, "type": "chapter", "uri": "",
+            "audiobook": {"authors": [{"name": "An Author"}], "copyrights": [], "description": "", "explicit": false, "external_urls": {"spotify": ""},
+            "href": "", "id": "book", "images": [], "languages": ["en"], "media_type": "audio", "name": "Book", "narrators": [],
+            "publisher": "", "type": "audiobook", "uri": ""}}"#)
+    }).await;
+
+    let mut client = ChapterData::from_provider(tokens, RateLimiter::new());
+    client.api_url(&url);
+
+    match client.get_chapter("chapter", Some("US")).run().await.unwrap() {
+        ChapterResult::Chapter(chapter) => {
+            assert_eq!(chapter.resume_point.unwrap().resume_position_ms, 2500);
+            assert_eq!(chapter.audiobook.unwrap().authors[0].name, "An Author");
+        }
+        other => panic!("Expected a chapter, got {:?}", other),
+    }
+}
@@ -0,0 +1,94 @@
+mod common;
+
+use common::{provider, serve, token, Reply, Request};
+
+use rustify::artist::ArtistResult;
+use rustify::core::ratelimits::RateLimiter;
+use rustify::SpotifyClient;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+fn artist(id: &str) -> String {
+    format!(r#"{{"external_urls": {{"spotify": ""}}, "href": "", "id": "{id}", "name": "Artist {id}", "type": "artist", "uri": ""}}"#)
+}
+
+// Counts the token requests and the api requests separately
+async fn client(tokens: Arc<AtomicUsize>, requests: Arc<AtomicUsize>, handler: impl Fn(Request) -> Reply + Send + Sync + 'static) -> SpotifyClient {
+    let url = serve(move |request| {
+        if request.path == "/api/token" {
+            tokens.fetch_add(1, Ordering::SeqCst);
+            token()
+        } else {
+            requests.fetch_add(1, Ordering::SeqCst);
+            handler(request)
+        }
+    }).await;
+    let tokens = provider(&url);
+    let mut client = SpotifyClient::from_provider(tokens, RateLimiter::new());
+    client.api_url(&url);
+    client
+}
+
+#[tokio::test]
+async fn sub_clients_share_the_token_and_cache() {
+    let tokens = Arc::new(AtomicUsize::new(0));
+    let requests = Arc::new(AtomicUsize::new(0));
+    let spotify = client(tokens.clone(), requests.clone(), |request| {
+        if request.path.starts_with("/artists/") {
+            Reply::json(200, &artist(request.path.trim_start_matches("/artists/")))
+        } else {
+            Reply::json(200, r#"{"markets": ["US", "GB"]}"#)
+        }
+    }).await;
+
+    let first = spotify.artists().get_artist("one").run().await.unwrap();
+    // A new sub-client is served from the cache the first one filled
+    let second = spotify.artists().get_artist("one").run().await.unwrap();
+    assert_eq!(first, second);
+    assert_eq!(second.name(), Some("Artist one".to_string()));
+    assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+    spotify.browse().get_markets().run().await.unwrap();
+    assert_eq!(requests.load(Ordering::SeqCst), 2);
+    assert_eq!(tokens.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn sub_clients_work_on_other_tasks() {
+    let tokens = Arc::new(AtomicUsize::new(0));
+    let requests = Arc::new(AtomicUsize::new(0));
+    let spotify = client(tokens.clone(), requests.clone(), |request| {
+        Reply::json(200, &artist(request.path.trim_start_matches("/artists/")))
+    }).await;
+
+    let tasks: Vec<_> = (0..8).map(|number| {
+        let mut artists = spotify.artists();
+        tokio::spawn(async move { artists.get_artist(&format!("artist{}", number)).run().await })
+    }).collect();
+    for (number, task) in tasks.into_iter().enumerate() {
+        match task.await.unwrap().unwrap() {
+            ArtistResult::Artist(artist) => assert_eq!(artist.id, format!("artist{}", number)),
+            other => panic!("Expected an artist, got {:?}", other),
+        }
+    }
+    assert_eq!(requests.load(Ordering::SeqCst), 8);
+    // The tasks all wanted the first token at once, the provider still only asked for it once
+    assert_eq!(tokens.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn tokens_and_api_calls_use_the_same_http_client() {
+    let url = serve(|request| {
+        // Only the client handed to the provider sends this header
+        assert_eq!(request.header("x-pool"), Some("shared"), "{} came from another client", request.path);
+        if request.path == "/api/token" { token() } else { Reply::json(200, r#"{"markets": ["US"]}"#) }
+    }).await;
+    let mut headers = reqwest::header::HeaderMap::new();
+    headers.insert("x-pool", reqwest::header::HeaderValue::from_static("shared"));
+    let http = reqwest::Client::builder().default_headers(headers).build().unwrap();
+
+    let mut spotify = SpotifyClient::from_provider(provider(&url).http_client(http), RateLimiter::new());
+    spotify.api_url(&url);
+    spotify.browse().get_markets().run().await.unwrap();
+}
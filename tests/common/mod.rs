@@ -0,0 +1,170 @@
+// A tiny stand-in for the spotify servers so the tests that don't need real credentials can run offline
+#![allow(dead_code)]
+
+use std::sync::Arc;
+
+use rustify::core::auth::TokenProvider;
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+#[derive(Debug, Clone)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl Request {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.iter().find(|(key, _)| key.eq_ignore_ascii_case(name)).map(|(_, value)| value.as_str())
+    }
+
+    pub fn query(&self, name: &str) -> Option<String> {
+        let query = self.path.split_once('?')?.1;
+        form_value(query, name)
+    }
+
+    pub fn form(&self, name: &str) -> Option<String> {
+        form_value(&self.body, name)
+    }
+}
+
+fn form_value(form: &str, name: &str) -> Option<String> {
+    form.split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(key, _)| *key == name)
+        .map(|(_, value)| value.replace("%2C", ",").replace("%3A", ":").replace("%20", " ").replace('+', " "))
+}
+
+pub struct Reply {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl Reply {
+    pub fn json(status: u16, body: &str) -> Reply {
+        Reply {
+            status,
+            headers: vec![("Content-Type".to_string(), "application/json".to_string())],
+            body: body.to_string(),
+        }
+    }
+
+    pub fn header(mut self, name: &str, value: &str) -> Reply {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+}
+
+/// Starts a server on a random local port and returns its base url (with no trailing slash)
+pub async fn serve<F>(handler: F) -> String
+where
+    F: Fn(Request) -> Reply + Send + Sync + 'static,
+{
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let handler = Arc::new(handler);
+    tokio::spawn(async move {
+        loop {
+            let (stream, _) = match listener.accept().await {
+                Ok(connection) => connection,
+                Err(_) => return,
+            };
+            let handler = handler.clone();
+            tokio::spawn(async move {
+                let _ = handle(stream, handler.as_ref()).await;
+            });
+        }
+    });
+    url
+}
+
+async fn handle<F>(mut stream: TcpStream, handler: &F) -> std::io::Result<()>
+where
+    F: Fn(Request) -> Reply,
+{
+    let mut data = Vec::new();
+    let mut buf = [0u8; 4096];
+    let header_end = loop {
+        let read = stream.read(&mut buf).await?;
+        if read == 0 {
+            return Ok(());
+        }
+        data.extend_from_slice(&buf[..read]);
+        if let Some(end) = data.windows(4).position(|window| window == b"\r\n\r\n") {
+            break end + 4;
+        }
+    };
+
+    let head = String::from_utf8_lossy(&data[..header_end]).to_string();
+    let mut lines = head.lines();
+    let mut start = lines.next().unwrap_or_default().split(' ');
+    let method = start.next().unwrap_or_default().to_string();
+    let path = start.next().unwrap_or_default().to_string();
+    let headers: Vec<(String, String)> = lines
+        .filter_map(|line| line.split_once(':'))
+        .map(|(key, value)| (key.trim().to_string(), value.trim().to_string()))
+        .collect();
+
+    let length = headers
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case("content-length"))
+        .and_then(|(_, value)| value.parse::<usize>().ok())
+        .unwrap_or(0);
+    while data.len() < header_end + length {
+        let read = stream.read(&mut buf).await?;
+        if read == 0 {
+            break;
+        }
+        data.extend_from_slice(&buf[..read]);
+    }
+    let body = String::from_utf8_lossy(&data[header_end..]).to_string();
+
+    let reply = handler(Request { method, path, headers, body });
+    let mut response = format!("HTTP/1.1 {} Status\r\nContent-Length: {}\r\nConnection: close\r\n", reply.status, reply.body.len());
+    for (key, value) in reply.headers {
+        response += &format!("{}: {}\r\n", key, value);
+    }
+    response += "\r\n";
+    response += &reply.body;
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await
+}
+
+/// A client credentials token, for stand-ins that also play the accounts service
+pub fn token() -> Reply {
+    Reply::json(200, r#"{"access_token": "token", "token_type": "Bearer", "expires_in": 3600}"#)
+}
+
+/// A client credentials provider that gets its tokens from the stand-in at `url`
+pub fn provider(url: &str) -> TokenProvider {
+    TokenProvider::client_credentials("id".to_string(), "secret".to_string()).token_url(&format!("{}/api/token", url))
+}
+
+/// Starts a stand-in for the web api that also hands out tokens, returns its url and a provider to use with it
+pub async fn api<F>(handler: F) -> (String, TokenProvider)
+where
+    F: Fn(Request) -> Reply + Send + Sync + 'static,
+{
+    let url = serve(move |request| if request.path == "/api/token" { token() } else { handler(request) }).await;
+    let tokens = provider(&url);
+    (url, tokens)
+}
+
+/// Writes the test file's `client(handler)`, making an endpoint client of type `$client` that sends everything to a stand-in
+#[allow(unused_macros)]
+macro_rules! client_for {
+    ($client:ty) => {
+        async fn client(handler: impl Fn($crate::common::Request) -> $crate::common::Reply + Send + Sync + 'static) -> $client {
+            let (url, tokens) = $crate::common::api(handler).await;
+            let mut client = <$client>::from_provider(tokens, rustify::core::ratelimits::RateLimiter::new());
+            client.api_url(&url);
+            client
+        }
+    };
+}
+#[allow(unused_imports)]
+pub(crate) use client_for;
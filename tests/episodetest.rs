@@ -0,0 +1,32 @@
+mod common;
+
+use common::{api, Reply};
+
+use rustify::core::ratelimits::RateLimiter;
+use rustify::core::structs::show_structs::ReleaseDatePrecision;
+use rustify::episode::{EpisodeData, EpisodeResult};
+
+#[tokio::test]
+async fn get_episodes_with_resume_points() {
+    let (url, tokens) = api(|request| {
+        assert_eq!(request.query("ids").as_deref(), Some("one,missing"));
+        Reply::json(200, r#"{"episodes": [{"audio_preview_url": null, "description": "", "duration_ms": 60000, "explicit": false,
+            "external_urls": {"spotify": ""}, "href": "", "id": "one", "images": [], "languages": ["en"], "name": "Episode",
+            "release_date": "2021-03-14", "release_date_precision": "day", "resume_point": {"fully_played": true, "resume_position_ms": 60000},
+            "type": "episode", "uri": ""}, null]}"#)
+    }).await;
+
+    let mut client = EpisodeData::from_provider(tokens, RateLimiter::new());
+    client.api_url(&url);
+
+    match client.get_episodes(&["one", "missing"], None).run().await.unwrap() {
+        EpisodeResult::Episodes(episodes) => {
+            let episode = episodes[0].as_ref().unwrap();
+            assert_eq!(episode.release_date_precision, ReleaseDatePrecision::Day);
+            assert_eq!(episode.release(), Some((2021, Some(3), Some(14))));
+            assert!(episode.resume_point.as_ref().unwrap().fully_played);
+            assert!(episodes[1].is_none());
+        }
+        other => panic!("Expected episodes, got {:?}", other),
+    }
+}
@@ -0,0 +1,70 @@
+mod common;
+
+use common::{client_for, Reply};
+
+use rustify::follow::{FollowData, FollowResult, FollowStatus, FollowType};
+
+client_for!(FollowData);
+
+fn artist(id: &str) -> String {
+    format!(r#"{{"external_urls": {{"spotify": ""}}, "href": "", "id": "{id}", "name": "Artist {id}", "type": "artist", "uri": ""}}"#)
+}
+
+#[tokio::test]
+async fn followed_artists_follow_the_cursor() {
+    let mut client = client(|request| {
+        assert_eq!(request.query("type").as_deref(), Some("artist"));
+        let (items, next) = match request.query("after").as_deref() {
+            None => (artist("one"), r#""/me/following?type=artist&after=one""#),
+            Some("one") => (artist("two"), "null"),
+            Some(after) => panic!("Unexpected cursor {}", after),
+        };
+        // The next link is relative here, so point it back at this server
+        let host = request.header("host").unwrap().to_string();
+        let next = next.replace("/me", &format!("http://{}/me", host));
+        Reply::json(200, &format!(r#"{{"artists": {{"href": "", "limit": 1, "next": {next}, "cursors": {{"after": null}}, "total": 2, "items": [{items}]}}}}"#))
+    }).await;
+
+    match client.get_followed_artists(None, Some(1)).all_pages(5).unwrap().run().await.unwrap() {
+        FollowResult::Artists(artists) => {
+            let ids: Vec<&str> = artists.items.iter().map(|artist| artist.id.as_str()).collect();
+            assert_eq!(ids, vec!["one", "two"]);
+            assert_eq!(artists.total, Some(2));
+        }
+        other => panic!("Expected artists, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn users_follow_playlist_pairs_ids_with_answers() {
+    let mut client = client(|request| {
+        assert!(request.path.starts_with("/playlists/list/followers/contains"));
+        let ids = request.query("ids").unwrap();
+        assert!(ids.split(',').count() <= 5);
+        let answers: Vec<&str> = ids.split(',').map(|id| if id.ends_with('1') { "true" } else { "false" }).collect();
+        Reply::json(200, &format!("[{}]", answers.join(",")))
+    }).await;
+
+    let users = ["user0", "user1", "user2", "user3", "user4", "user5", "user11"];
+    match client.users_follow_playlist("list", &users).run().await.unwrap() {
+        FollowResult::Following(following) => {
+            assert_eq!(following.len(), 7);
+            assert_eq!(following[1], FollowStatus { id: "user1".to_string(), following: true });
+            assert_eq!(following[6], FollowStatus { id: "user11".to_string(), following: true });
+            assert!(!following[5].following);
+        }
+        other => panic!("Expected following, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn follow_users() {
+    let mut client = client(|request| {
+        assert_eq!(request.method, "PUT");
+        assert_eq!(request.query("type").as_deref(), Some("user"));
+        assert_eq!(request.query("ids").as_deref(), Some("a,b"));
+        Reply::json(204, "")
+    }).await;
+
+    assert_eq!(client.follow(FollowType::User, &["a", "b"]).run().await.unwrap(), FollowResult::Null);
+}
@@ -0,0 +1,76 @@
+mod common;
+
+use common::{client_for, Reply};
+
+use rustify::library::{LibraryData, LibraryResult, LibraryType};
+
+use std::sync::{Arc, Mutex};
+
+client_for!(LibraryData);
+
+fn ids(count: usize) -> Vec<String> {
+    (0..count).map(|number| format!("id{}", number)).collect()
+}
+
+#[tokio::test]
+async fn contains_in_order() {
+    let mut client = client(|request| {
+        assert!(request.path.starts_with("/me/albums/contains"));
+        let ids = request.query("ids").unwrap();
+        let ids: Vec<&str> = ids.split(',').collect();
+        assert!(ids.len() <= 20);
+        // Only the even ids are saved
+        let saved: Vec<String> = ids.iter().map(|id| (id[2..].parse::<usize>().unwrap() % 2 == 0).to_string()).collect();
+        Reply::json(200, &format!("[{}]", saved.join(",")))
+    }).await;
+
+    let ids = ids(45);
+    let ids: Vec<&str> = ids.iter().map(String::as_str).collect();
+    match client.contains(LibraryType::Albums, &ids).run().await.unwrap() {
+        LibraryResult::Contains(saved) => {
+            assert_eq!(saved.len(), 45);
+            assert!(saved.iter().enumerate().all(|(index, saved)| *saved == (index % 2 == 0)));
+        }
+        other => panic!("Expected contains, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn save_and_remove_in_chunks() {
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let seen = requests.clone();
+    let mut client = client(move |request| {
+        assert!(request.path.starts_with("/me/tracks?"));
+        let count = request.query("ids").unwrap().split(',').count();
+        seen.lock().unwrap().push((request.method.clone(), count));
+        Reply::json(200, "")
+    }).await;
+
+    let ids = ids(120);
+    let ids: Vec<&str> = ids.iter().map(String::as_str).collect();
+    assert_eq!(client.save(LibraryType::Tracks, &ids).run().await.unwrap(), LibraryResult::Null);
+    client.remove(LibraryType::Tracks, &ids[..10]).run().await.unwrap();
+
+    let requests = requests.lock().unwrap();
+    let expected = vec![("PUT".to_string(), 50), ("PUT".to_string(), 50), ("PUT".to_string(), 20), ("DELETE".to_string(), 10)];
+    assert_eq!(*requests, expected);
+}
+
+#[tokio::test]
+async fn saved_shows() {
+    let mut client = client(|request| {
+        assert!(request.path.starts_with("/me/shows"));
+        Reply::json(200, r#"{"href": "", "limit": 20, "next": null, "offset": 0, "previous": null, "total": 1, "items": [{"added_at": "2024-01-01T00:00:00Z",
+            "show": {"available_markets": ["US"], "copyrights": [], "description": "A show", "html_description": "<p>A show</p>", "explicit": false,
+            "external_urls": {"spotify": ""}, "href": "", "id": "show", "images": [], "is_externally_hosted": false, "languages": ["en"],
+            "media_type": "audio", "name": "Show", "publisher": "Someone", "type": "show", "uri": "", "total_episodes": 10}}]}"#)
+    }).await;
+
+    match client.get_saved(LibraryType::Shows, None, None, None).run().await.unwrap() {
+        LibraryResult::Shows(shows) => {
+            assert_eq!(shows.items[0].show.languages, vec!["en"]);
+            assert_eq!(shows.items[0].show.total_episodes, 10);
+        }
+        other => panic!("Expected shows, got {:?}", other),
+    }
+}
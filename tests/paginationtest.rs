@@ -0,0 +1,108 @@
+mod common;
+
+use common::{client_for, Reply, Request};
+
+use rustify::artist::{ArtistData, ArtistRequest, ArtistResult};
+use rustify::core::structs::paging::CursorPaging;
+use rustify::Error;
+
+use futures::TryStreamExt;
+
+const TOTAL: usize = 5;
+
+fn album(number: usize) -> String {
+    format!(r#"{{"album_type": "album", "total_tracks": 10, "available_markets": [], "external_urls": {{"spotify": ""}}, "href": "", "id": "album{number}",
+        "images": [], "name": "Album {number}", "release_date": "2020", "release_date_precision": "year", "type": "album", "uri": "", "artists": [], "album_group": "album"}}"#)
+}
+
+// Pages of two albums out of five, with next links back to the stand-in
+fn albums(request: Request) -> Reply {
+    let offset: usize = request.query("offset").and_then(|offset| offset.parse().ok()).unwrap_or(0);
+    let limit: usize = request.query("limit").and_then(|limit| limit.parse().ok()).unwrap_or(2).min(2);
+    let items: Vec<String> = (offset..(offset + limit).min(TOTAL)).map(album).collect();
+    let next = if offset + limit < TOTAL {
+        format!(r#""http://{}/artists/artist/albums?offset={}&limit={}""#, request.header("host").unwrap(), offset + limit, limit)
+    }
+    else {
+        "null".to_string()
+    };
+    Reply::json(200, &format!(r#"{{"href": "", "limit": {limit}, "next": {next}, "offset": {offset}, "previous": null, "total": {TOTAL}, "items": [{}]}}"#, items.join(",")))
+}
+
+client_for!(ArtistData);
+
+#[tokio::test]
+async fn one_page_by_default() {
+    let mut client = client(albums).await;
+    match client.get_albums("artist", None, None, Some(2), None).run().await.unwrap() {
+        ArtistResult::Albums(albums) => {
+            assert_eq!(albums.items.len(), 2);
+            assert!(albums.next.is_some());
+        }
+        other => panic!("Expected albums, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn all_pages() {
+    let mut client = client(albums).await;
+    match client.get_albums("artist", None, None, Some(2), None).all_pages(10).unwrap().run().await.unwrap() {
+        ArtistResult::Albums(albums) => {
+            let ids: Vec<String> = albums.items.iter().map(|album| album.id.clone()).collect();
+            assert_eq!(ids, vec!["album0", "album1", "album2", "album3", "album4"]);
+            assert_eq!(albums.total, TOTAL as i32);
+            assert_eq!(albums.next, None);
+        }
+        other => panic!("Expected albums, got {:?}", other),
+    }
+
+    // all_pages only lasts for one request
+    let result = client.get_albums("artist", None, None, Some(2), None).run().await.unwrap();
+    assert_eq!(result.ids().map(|ids| ids.len()), Some(2));
+}
+
+#[tokio::test]
+async fn page_cap() {
+    let mut client = client(albums).await;
+    match client.get_albums("artist", None, None, Some(2), None).all_pages(2).unwrap().run().await.unwrap() {
+        ArtistResult::Albums(albums) => {
+            assert_eq!(albums.items.len(), 4);
+            assert_eq!(albums.total, TOTAL as i32);
+            assert!(albums.next.is_some());
+        }
+        other => panic!("Expected albums, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn all_pages_with_execute() {
+    let mut client = client(albums).await;
+    let request = ArtistRequest::albums("artist", None, None, Some(2), None).all_pages(10).unwrap();
+    assert_eq!(client.execute(request).await.unwrap().ids().map(|ids| ids.len()), Some(TOTAL));
+
+    // all_pages needs a paged request set up before it, and doesn't carry over to the next one
+    assert!(matches!(client.all_pages(10), Err(Error::InvalidArguments(_))));
+    let result = client.get_albums("artist", None, None, Some(2), None).run().await.unwrap();
+    assert_eq!(result.ids().map(|ids| ids.len()), Some(2));
+}
+
+#[test]
+fn all_pages_needs_pages() {
+    assert!(matches!(ArtistRequest::artist("artist").all_pages(10), Err(Error::InvalidArguments(_))));
+}
+
+#[tokio::test]
+async fn album_stream() {
+    let client = client(albums).await;
+    let albums: Vec<_> = client.album_stream("artist", Some(vec!["album", "single"]), None).try_collect().await.unwrap();
+    assert_eq!(albums.len(), TOTAL);
+    assert_eq!(albums[4].name, "Album 4");
+}
+
+#[test]
+fn cursor_paging() {
+    let page: CursorPaging<String> = serde_json::from_str(r#"{"href": "", "limit": 2, "next": "next-link", "cursors": {"after": "abc"}, "total": 4, "items": ["one", "two"]}"#).unwrap();
+    assert_eq!(page.items, vec!["one", "two"]);
+    assert_eq!(page.cursors.and_then(|cursors| cursors.after).as_deref(), Some("abc"));
+    assert_eq!(page.total, Some(4));
+}
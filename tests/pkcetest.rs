@@ -0,0 +1,94 @@
+mod common;
+
+use common::{serve, Reply};
+
+use rustify::core::auth::pkce::AuthCodePkce;
+use rustify::core::auth::AuthError;
+use rustify::Error;
+
+use std::sync::{Arc, Mutex};
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use reqwest::Url;
+use sha2::{Digest, Sha256};
+
+fn free_port() -> u16 {
+    std::net::TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port()
+}
+
+fn query(url: &str, name: &str) -> Option<String> {
+    Url::parse(url).unwrap().query_pairs().find(|(key, _)| key == name).map(|(_, value)| value.to_string())
+}
+
+#[tokio::test]
+async fn full_flow() {
+    let challenge = Arc::new(Mutex::new(String::new()));
+    let expected = challenge.clone();
+    let accounts = serve(move |request| {
+        assert_eq!(request.path, "/api/token");
+        assert_eq!(request.form("grant_type").as_deref(), Some("authorization_code"));
+        assert_eq!(request.form("code").as_deref(), Some("the-code"));
+        let verifier = request.form("code_verifier").unwrap();
+        if URL_SAFE_NO_PAD.encode(Sha256::digest(verifier.as_bytes())) != *expected.lock().unwrap() {
+            return Reply::json(400, r#"{"error": "invalid_grant"}"#);
+        }
+        Reply::json(200, r#"{"access_token": "user-token", "token_type": "Bearer", "scope": "user-read-private playlist-modify-public", "expires_in": 3600, "refresh_token": "refresh"}"#)
+    }).await;
+
+    let auth = AuthCodePkce::new("client".to_string(), free_port())
+        .scopes(&["user-read-private", "playlist-modify-public"])
+        .accounts_url(&accounts);
+
+    let authorize = auth.authorize_url().unwrap();
+    assert!(authorize.starts_with(&format!("{}/authorize?", accounts)));
+    assert_eq!(query(&authorize, "client_id").as_deref(), Some("client"));
+    assert_eq!(query(&authorize, "code_challenge_method").as_deref(), Some("S256"));
+    assert_eq!(query(&authorize, "scope").as_deref(), Some("user-read-private playlist-modify-public"));
+    assert_eq!(query(&authorize, "redirect_uri"), Some(auth.redirect_uri()));
+    assert_eq!(query(&authorize, "state").as_deref(), Some(auth.state()));
+    *challenge.lock().unwrap() = query(&authorize, "code_challenge").unwrap();
+
+    let listener = auth.listen().await.unwrap();
+    // Stands in for the browser following spotify's redirect
+    let redirect = format!("{}?code=the-code&state={}", auth.redirect_uri(), auth.state());
+    let browser = tokio::spawn(async move { reqwest::get(redirect).await.unwrap().status() });
+
+    let code = listener.code().await.unwrap();
+    assert_eq!(code, "the-code");
+    assert!(browser.await.unwrap().is_success());
+
+    let token = auth.exchange(&code).await.unwrap();
+    assert_eq!(token.access_token, "user-token");
+    assert_eq!(token.refresh_token.as_deref(), Some("refresh"));
+    assert_eq!(token.scopes, vec!["user-read-private", "playlist-modify-public"]);
+}
+
+#[tokio::test]
+async fn wrong_state() {
+    let auth = AuthCodePkce::new("client".to_string(), free_port());
+    let listener = auth.listen().await.unwrap();
+    let redirect = format!("{}?code=the-code&state=not-the-state", auth.redirect_uri());
+    tokio::spawn(async move { reqwest::get(redirect).await });
+
+    assert!(matches!(listener.code().await, Err(Error::Auth(AuthError::StateMismatch))));
+}
+
+#[tokio::test]
+async fn denied() {
+    let auth = AuthCodePkce::new("client".to_string(), free_port());
+    let listener = auth.listen().await.unwrap();
+    let redirect = format!("{}?error=access_denied&state={}", auth.redirect_uri(), auth.state());
+    tokio::spawn(async move { reqwest::get(redirect).await });
+
+    match listener.code().await {
+        Err(Error::Auth(AuthError::Denied(reason))) => assert_eq!(reason, "access_denied"),
+        other => panic!("Expected the login to be denied, got {:?}", other),
+    }
+}
+
+#[test]
+fn bad_accounts_url() {
+    let auth = AuthCodePkce::new("client".to_string(), 8888).accounts_url("not a url");
+    assert!(matches!(auth.authorize_url(), Err(Error::InvalidArguments(_))));
+}
@@ -0,0 +1,74 @@
+mod common;
+
+use common::{client_for, Reply};
+
+use rustify::core::structs::player_structs::{CurrentlyPlayingType, PlayingItem, RepeatState};
+use rustify::player::{PlayerData, PlayerResult, StartPlayback, TimeCursor};
+
+client_for!(PlayerData);
+
+#[tokio::test]
+async fn playback_state_with_an_episode() {
+    let mut client = client(|request| {
+        assert!(request.path.starts_with("/me/player?"));
+        assert_eq!(request.query("additional_types").as_deref(), Some("track,episode"));
+        Reply::json(200, r#"{"device": {"id": "phone", "is_active": true, "is_private_session": false, "is_restricted": false, "name": "Phone",
+            "type": "smartphone", "volume_percent": 50, "supports_volume": true}, "repeat_state": "context", "shuffle_state": true, "context": null,
+            "timestamp": 1700000000000, "progress_ms": 1000, "is_playing": true, "currently_playing_type": "episode",
+            "actions": {"disallows": {"resuming": true}},
+            "item": {"audio_preview_url": null, "description": "", "duration_ms": 60000, "explicit": false, "external_urls": {"spotify": ""},
+            "href": "", "id": "episode", "images": [], "is_externally_hosted": false, "is_playable": true, "languages": ["en"], "name": "Episode",
+            "release_date": "2024", "release_date_precision": "year", "resume_point": {"fully_played": false, "resume_position_ms": 1000},
+            "type": "episode", "uri": "spotify:episode:episode"}}"#)
+    }).await;
+
+    match client.get_playback_state(None).run().await.unwrap() {
+        PlayerResult::State(Some(state)) => {
+            assert_eq!(state.currently_playing_type, CurrentlyPlayingType::Episode);
+            assert_eq!(state.repeat_state, RepeatState::Context);
+            assert_eq!(state.device.volume_percent, Some(50));
+            assert!(matches!(state.item, Some(PlayingItem::Episode(_))));
+            assert_eq!(state.item.unwrap().id(), "episode");
+            assert_eq!(state.actions.unwrap().disallows.unwrap().resuming, Some(true));
+        }
+        other => panic!("Expected a playback state, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn nothing_playing() {
+    let mut client = client(|_| Reply::json(204, "")).await;
+    assert_eq!(client.get_currently_playing(None).run().await.unwrap(), PlayerResult::CurrentlyPlaying(None));
+}
+
+#[tokio::test]
+async fn controls() {
+    let mut client = client(|request| {
+        match request.method.as_str() {
+            "PUT" if request.path.starts_with("/me/player/play") => {
+                assert_eq!(request.query("device_id").as_deref(), Some("phone"));
+                let body: serde_json::Value = serde_json::from_str(&request.body).unwrap();
+                assert_eq!(body, serde_json::json!({ "context_uri": "spotify:album:one", "offset": { "position": 3 } }));
+            }
+            "PUT" if request.path.starts_with("/me/player/repeat") => assert_eq!(request.query("state").as_deref(), Some("track")),
+            "GET" => {
+                assert_eq!(request.query("before").as_deref(), Some("1700000000000"));
+                assert_eq!(request.query("after"), None);
+                return Reply::json(200, r#"{"href": "", "limit": 20, "next": null, "cursors": {"after": "1", "before": "0"}, "items": []}"#);
+            }
+            _ => panic!("Unexpected request {} {}", request.method, request.path),
+        }
+        Reply::json(204, "")
+    }).await;
+
+    let playback = StartPlayback::new().context("spotify:album:one").offset_position(3);
+    assert_eq!(client.play(Some("phone"), playback).run().await.unwrap(), PlayerResult::Null);
+    client.repeat(RepeatState::Track, None).run().await.unwrap();
+    match client.get_recently_played(None, Some(TimeCursor::Before(1700000000000))).run().await.unwrap() {
+        PlayerResult::RecentlyPlayed(history) => assert_eq!(history.cursors.unwrap().before.as_deref(), Some("0")),
+        other => panic!("Expected recently played, got {:?}", other),
+    }
+
+    // Nothing is sent for a volume that can't be set
+    assert!(client.volume(101, None).run().await.is_err());
+}
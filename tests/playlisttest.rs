@@ -0,0 +1,121 @@
+mod common;
+
+use common::{client_for, Reply};
+
+use rustify::playlist::{PlaylistData, PlaylistDetails, PlaylistResult};
+
+use std::sync::{Arc, Mutex};
+
+client_for!(PlaylistData);
+
+#[tokio::test]
+async fn add_items_in_chunks_keeping_position() {
+    let bodies = Arc::new(Mutex::new(Vec::new()));
+    let seen = bodies.clone();
+    let mut client = client(move |request| {
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.path, "/playlists/list/tracks");
+        let body: serde_json::Value = serde_json::from_str(&request.body).unwrap();
+        let mut seen = seen.lock().unwrap();
+        seen.push(body);
+        Reply::json(201, &format!(r#"{{"snapshot_id": "snapshot{}"}}"#, seen.len()))
+    }).await;
+
+    let uris: Vec<String> = (0..250).map(|number| format!("spotify:track:{}", number)).collect();
+    let uris: Vec<&str> = uris.iter().map(String::as_str).collect();
+
+    let result = client.add_items("list", &uris, Some(5)).run().await.unwrap();
+    assert_eq!(result, PlaylistResult::Snapshot("snapshot3".to_string()));
+
+    let bodies = bodies.lock().unwrap();
+    let sizes: Vec<usize> = bodies.iter().map(|body| body["uris"].as_array().unwrap().len()).collect();
+    assert_eq!(sizes, vec![100, 100, 50]);
+    let positions: Vec<i64> = bodies.iter().map(|body| body["position"].as_i64().unwrap()).collect();
+    assert_eq!(positions, vec![5, 105, 205]);
+    assert_eq!(bodies[1]["uris"][0], "spotify:track:100");
+}
+
+#[tokio::test]
+async fn remove_items_passes_the_newest_snapshot_on() {
+    let bodies = Arc::new(Mutex::new(Vec::new()));
+    let seen = bodies.clone();
+    let mut client = client(move |request| {
+        assert_eq!(request.method, "DELETE");
+        let body: serde_json::Value = serde_json::from_str(&request.body).unwrap();
+        let mut seen = seen.lock().unwrap();
+        seen.push(body);
+        Reply::json(200, &format!(r#"{{"snapshot_id": "after{}"}}"#, seen.len()))
+    }).await;
+
+    let uris: Vec<String> = (0..150).map(|number| format!("spotify:track:{}", number)).collect();
+    let uris: Vec<&str> = uris.iter().map(String::as_str).collect();
+
+    let result = client.remove_items("list", &uris, Some("before")).run().await.unwrap();
+    assert_eq!(result, PlaylistResult::Snapshot("after2".to_string()));
+
+    let bodies = bodies.lock().unwrap();
+    assert_eq!(bodies[0]["snapshot_id"], "before");
+    assert_eq!(bodies[1]["snapshot_id"], "after1");
+    assert_eq!(bodies[0]["tracks"][0]["uri"], "spotify:track:0");
+    assert_eq!(bodies[1]["tracks"].as_array().unwrap().len(), 50);
+}
+
+#[tokio::test]
+async fn change_details_sends_only_what_was_set() {
+    let mut client = client(|request| {
+        assert_eq!(request.method, "PUT");
+        assert_eq!(request.path, "/playlists/list");
+        let body: serde_json::Value = serde_json::from_str(&request.body).unwrap();
+        assert_eq!(body, serde_json::json!({ "name": "New name", "public": false }));
+        Reply::json(200, "")
+    }).await;
+
+    let details = PlaylistDetails::new().name("New name").public(false);
+    assert_eq!(client.change_details("list", details).run().await.unwrap(), PlaylistResult::Null);
+
+    // Creating a playlist without a name can never work
+    assert!(client.create_playlist("user", PlaylistDetails::new()).run().await.is_err());
+}
+
+#[tokio::test]
+async fn reorder_leaves_out_a_missing_snapshot() {
+    let mut client = client(|request| {
+        assert_eq!(request.method, "PUT");
+        let body: serde_json::Value = serde_json::from_str(&request.body).unwrap();
+        assert_eq!(body, serde_json::json!({ "range_start": 3, "insert_before": 0, "range_length": 1 }));
+        Reply::json(200, r#"{"snapshot_id": "moved"}"#)
+    }).await;
+
+    let result = client.reorder_items("list", 3, 0, None, None).run().await.unwrap();
+    assert_eq!(result, PlaylistResult::Snapshot("moved".to_string()));
+}
+
+// Local files have nulls where spotify would have ids and album details
+const LOCAL_FILE: &str = r#"{"added_at": "2023-01-01T00:00:00Z", "added_by": null, "is_local": true, "track": {"album": {"album_type": null,
+    "artists": [], "available_markets": [], "external_urls": {}, "href": null, "id": null, "images": [], "name": "", "release_date": null,
+    "release_date_precision": null, "type": "album", "uri": null}, "artists": [{"external_urls": {}, "href": null, "id": null, "name": "Someone",
+    "type": "artist", "uri": null}], "available_markets": [], "disc_number": 0, "duration_ms": 180000, "explicit": false, "external_ids": {},
+    "external_urls": {}, "href": null, "id": null, "is_local": true, "name": "Demo", "popularity": 0, "preview_url": null, "track_number": 0,
+    "type": "track", "uri": "spotify:local:Someone::Demo:180"}}"#;
+
+#[tokio::test]
+async fn items_with_local_files() {
+    let mut client = client(|request| {
+        assert!(request.path.starts_with("/playlists/list/tracks"));
+        Reply::json(200, &format!(r#"{{"href": "", "limit": 100, "next": null, "offset": 0, "previous": null, "total": 2, "items": [{}, {{"added_at": null,
+            "added_by": null, "is_local": false, "track": null}}]}}"#, LOCAL_FILE))
+    }).await;
+
+    match client.get_items("list", None, None, None).run().await.unwrap() {
+        PlaylistResult::Items(items) => {
+            let track = items.items[0].track.as_ref().unwrap();
+            assert!(track.is_local);
+            assert_eq!(track.id, None);
+            assert_eq!(track.album.release_date, None);
+            assert_eq!(track.artists[0].name, "Someone");
+            assert_eq!(track.uri, "spotify:local:Someone::Demo:180");
+            assert!(items.items[1].track.is_none());
+        }
+        other => panic!("Expected items, got {:?}", other),
+    }
+}
@@ -0,0 +1,84 @@
+mod common;
+
+use common::{serve, Reply};
+
+use rustify::core::ratelimits::{RateLimiter, RetryPolicy};
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use reqwest::{Client, StatusCode};
+
+fn policy(max_retries: u32) -> RetryPolicy {
+    RetryPolicy {
+        max_retries,
+        base_delay: Duration::from_millis(10),
+        max_delay: Duration::from_secs(2),
+    }
+}
+
+#[tokio::test]
+async fn retries_after_429() {
+    let hits = Arc::new(AtomicUsize::new(0));
+    let counter = hits.clone();
+    let url = serve(move |_| {
+        if counter.fetch_add(1, Ordering::SeqCst) < 2 {
+            Reply::json(429, "").header("Retry-After", "0")
+        }
+        else {
+            Reply::json(200, "{}")
+        }
+    }).await;
+
+    let limiter = RateLimiter::new().retry_policy(policy(3));
+    let response = limiter.send(Client::new().get(url)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(hits.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn honours_retry_after() {
+    let hits = Arc::new(AtomicUsize::new(0));
+    let counter = hits.clone();
+    let url = serve(move |_| {
+        if counter.fetch_add(1, Ordering::SeqCst) == 0 {
+            Reply::json(429, "").header("Retry-After", "1")
+        }
+        else {
+            Reply::json(200, "{}")
+        }
+    }).await;
+
+    let start = Instant::now();
+    let limiter = RateLimiter::new().retry_policy(policy(1));
+    let response = limiter.send(Client::new().get(url)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(start.elapsed() >= Duration::from_secs(1));
+}
+
+#[tokio::test]
+async fn gives_up_after_max_retries() {
+    let hits = Arc::new(AtomicUsize::new(0));
+    let counter = hits.clone();
+    let url = serve(move |_| {
+        counter.fetch_add(1, Ordering::SeqCst);
+        Reply::json(429, "")
+    }).await;
+
+    let limiter = RateLimiter::new().retry_policy(policy(2));
+    let response = limiter.send(Client::new().get(url)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(hits.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn token_bucket_waits() {
+    let limiter = RateLimiter::new().token_bucket(2, Duration::from_millis(200));
+    let start = Instant::now();
+    for _ in 0..4 {
+        limiter.acquire().await;
+    }
+    // The first two are free, the next two need a full window to refill
+    assert!(start.elapsed() >= Duration::from_millis(180));
+}
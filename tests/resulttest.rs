@@ -0,0 +1,69 @@
+use rustify::artist::ArtistResult;
+use rustify::core::structs::artist_structs::{Album, Albums, Artist, Artists, ExternalUrls, Followers, Item, TopTracks, Track};
+
+fn artist(name: &str, popularity: Option<i32>) -> Artist {
+    Artist {
+        name: name.to_string(),
+        id: format!("{}-id", name),
+        popularity,
+        genres: Some(vec!["pop".to_string()]),
+        followers: Some(Followers { href: None, total: 10 }),
+        external_urls: ExternalUrls { spotify: format!("https://open.spotify.com/artist/{}-id", name) },
+        ..Default::default()
+    }
+}
+
+#[test]
+fn single_artist() {
+    let result = ArtistResult::Artist(artist("one", Some(50)));
+    assert_eq!(result.name().as_deref(), Some("one"));
+    assert_eq!(result.id().as_deref(), Some("one-id"));
+    assert_eq!(result.popularity(), Some(50));
+    assert_eq!(result.genres(), Some(vec!["pop".to_string()]));
+    assert_eq!(result.followers().map(|followers| followers.total), Some(10));
+    assert_eq!(result.images(), None);
+    assert_eq!(result.external_url().as_deref(), Some("https://open.spotify.com/artist/one-id"));
+
+    // Not a list so the list accessors have nothing to give back
+    assert_eq!(result.names(), None);
+    assert_eq!(result.popularitys(), None);
+}
+
+#[test]
+fn many_artists() {
+    let result = ArtistResult::Artists(vec![Some(artist("one", Some(50))), Some(artist("two", None))]);
+    assert_eq!(result.name(), None);
+    assert_eq!(result.names(), Some(vec![Some("one".to_string()), Some("two".to_string())]));
+    assert_eq!(result.ids(), Some(vec![Some("one-id".to_string()), Some("two-id".to_string())]));
+    // One of the artists has no popularity, the other one's is still there
+    assert_eq!(result.popularitys(), Some(vec![Some(50), None]));
+    assert_eq!(result.all_followers().map(|followers| followers.len()), Some(2));
+
+    // Ids spotify didn't know stay in their place so everything lines up with the ids asked for
+    let unknown = ArtistResult::Artists(vec![None, Some(artist("one", Some(50))), None]);
+    assert_eq!(unknown.names(), Some(vec![None, Some("one".to_string()), None]));
+    assert_eq!(unknown.popularitys(), Some(vec![None, Some(50), None]));
+
+    let related = ArtistResult::RelatedArtists(Artists { artists: vec![artist("three", Some(1))] });
+    assert_eq!(related.popularitys(), Some(vec![Some(1)]));
+    assert_eq!(related.all_genres(), Some(vec![Some(vec!["pop".to_string()])]));
+}
+
+#[test]
+fn albums_and_tracks() {
+    let albums = ArtistResult::Albums(Albums {
+        items: vec![Item { name: "album".to_string(), id: "album-id".to_string(), ..Default::default() }],
+        ..Default::default()
+    });
+    assert_eq!(albums.names(), Some(vec![Some("album".to_string())]));
+    assert_eq!(albums.all_images(), Some(vec![Some(Vec::new())]));
+    assert_eq!(albums.popularitys(), None);
+    assert_eq!(albums.all_genres(), None);
+
+    let tracks = ArtistResult::TopTracks(TopTracks {
+        tracks: vec![Track { name: "track".to_string(), popularity: 70, album: Album::default(), ..Default::default() }],
+    });
+    assert_eq!(tracks.names(), Some(vec![Some("track".to_string())]));
+    assert_eq!(tracks.popularitys(), Some(vec![Some(70)]));
+    assert_eq!(tracks.external_urls(), Some(vec![Some(String::new())]));
+}
@@ -0,0 +1,59 @@
+mod common;
+
+use common::{client_for, Reply};
+
+use rustify::search::{SearchData, SearchQuery, SearchType};
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+client_for!(SearchData);
+
+#[tokio::test]
+async fn search_several_types() {
+    let requests = Arc::new(AtomicUsize::new(0));
+    let counter = requests.clone();
+    let mut client = client(move |request| {
+        counter.fetch_add(1, Ordering::SeqCst);
+        assert!(request.path.starts_with("/search?"));
+        assert_eq!(request.query("q").unwrap().replace("%22", "\""), r#"daft artist:"Daft Punk" year:1990-2000 tag:new"#);
+        assert_eq!(request.query("type").as_deref(), Some("artist,playlist,episode"));
+        assert_eq!(request.query("market").as_deref(), Some("FR"));
+        assert_eq!(request.query("include_external").as_deref(), Some("audio"));
+        Reply::json(200, r#"{
+            "artists": {"href": "", "limit": 1, "next": null, "offset": 0, "previous": null, "total": 1, "items": [
+                {"external_urls": {"spotify": ""}, "href": "", "id": "daft", "name": "Daft Punk", "type": "artist", "uri": ""}]},
+            "playlists": {"href": "", "limit": 1, "next": null, "offset": 0, "previous": null, "total": 1, "items": [null]},
+            "episodes": {"href": "", "limit": 1, "next": null, "offset": 0, "previous": null, "total": 1, "items": [
+                {"audio_preview_url": null, "description": "", "duration_ms": 60000, "explicit": false, "external_urls": {"spotify": ""}, "href": "",
+                "id": "episode", "images": [], "is_externally_hosted": true, "languages": ["fr"], "name": "Episode", "release_date": "2020",
+                "release_date_precision": "year", "type": "episode", "uri": ""}]}
+        }"#)
+    }).await;
+
+    let query = SearchQuery::new("daft")
+        .artist("Daft Punk")
+        .years(1990, 2000)
+        .tag_new()
+        .types(&[SearchType::Artist, SearchType::Playlist, SearchType::Episode])
+        .market("FR")
+        .include_external(true);
+
+    let results = client.search(query.clone()).run().await.unwrap();
+    assert_eq!(results.artists.unwrap().items[0].name, "Daft Punk");
+    assert_eq!(results.playlists.unwrap().items, vec![None]);
+    assert_eq!(results.episodes.unwrap().items[0].as_ref().unwrap().is_externally_hosted, Some(true));
+    assert!(results.tracks.is_none());
+    assert!(results.shows.is_none());
+
+    // The same search again comes from the cache
+    client.search(query).run().await.unwrap();
+    assert_eq!(requests.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn search_needs_a_type() {
+    let mut client = client(|_| panic!("Nothing should be sent")).await;
+    assert!(client.search(SearchQuery::new("daft")).run().await.is_err());
+    assert!(client.search(SearchQuery::new(" ").types(&[SearchType::Track])).run().await.is_err());
+}
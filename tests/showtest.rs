@@ -0,0 +1,72 @@
+mod common;
+
+use common::{client_for, Reply};
+
+use rustify::show::{ShowData, ShowResult};
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+client_for!(ShowData);
+
+fn episode(id: &str) -> String {
+    format!(r#"{{"audio_preview_url": null, "description": "", "html_description": "", "duration_ms": 60000, "explicit": false,
+        "external_urls": {{"spotify": ""}}, "href": "", "id": "{id}", "images": [], "is_externally_hosted": false, "is_playable": true,
+        "languages": ["en", "mi"], "name": "Episode {id}", "release_date": "2021-03", "release_date_precision": "month", "type": "episode", "uri": ""}}"#)
+}
+
+#[tokio::test]
+async fn get_show_with_episodes() {
+    let mut client = client(|request| {
+        assert!(request.path.starts_with("/shows/show?"));
+        assert_eq!(request.query("market").as_deref(), Some("NZ"));
+        Reply::json(200, &format!(r#"{{"available_markets": ["NZ"], "copyrights": [], "description": "", "explicit": false, "external_urls": {{"spotify": ""}},
+            "href": "", "id": "show", "images": [], "languages": ["en"], "media_type": "audio", "name": "Show", "publisher": "Someone",
+            "type": "show", "uri": "", "total_episodes": 1,
+            "episodes": {{"href": "", "limit": 50, "next": null, "offset": 0, "previous": null, "total": 1, "items": [{}]}}}}"#, episode("one")))
+    }).await;
+
+    match client.get_show("show", Some("NZ")).run().await.unwrap() {
+        ShowResult::Show(show) => {
+            let episode = &show.episodes.items[0];
+            assert_eq!(episode.languages, vec!["en", "mi"]);
+            assert_eq!(episode.release(), Some((2021, Some(3), None)));
+            assert!(episode.resume_point.is_none());
+        }
+        other => panic!("Expected a show, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn shows_are_not_cached() {
+    // The user keeps listening between the two requests, so the resume point has moved on
+    let listened = AtomicUsize::new(0);
+    let mut client = client(move |_| {
+        let position = listened.fetch_add(1, Ordering::SeqCst) * 1000;
+        let episode = episode("one").replace(r#""type": "episode""#, &format!(r#""resume_point": {{"fully_played": false, "resume_position_ms": {position}}}, "type": "episode""#));
+        Reply::json(200, &format!(r#"{{"available_markets": [], "copyrights": [], "description": "", "explicit": false, "external_urls": {{"spotify": ""}},
+            "href": "", "id": "show", "images": [], "languages": ["en"], "media_type": "audio", "name": "Show", "publisher": "Someone",
+            "type": "show", "uri": "", "total_episodes": 1,
+            "episodes": {{"href": "", "limit": 50, "next": null, "offset": 0, "previous": null, "total": 1, "items": [{}]}}}}"#, episode))
+    }).await;
+
+    for position in [0, 1000] {
+        match client.get_show("show", None).run().await.unwrap() {
+            ShowResult::Show(show) => assert_eq!(show.episodes.items[0].resume_point.as_ref().unwrap().resume_position_ms, position),
+            other => panic!("Expected a show, got {:?}", other),
+        }
+    }
+}
+
+#[tokio::test]
+async fn get_episodes_page() {
+    let mut client = client(|request| {
+        assert!(request.path.starts_with("/shows/show/episodes"));
+        assert_eq!(request.query("limit").as_deref(), Some("2"));
+        Reply::json(200, &format!(r#"{{"href": "", "limit": 2, "next": null, "offset": 0, "previous": null, "total": 2, "items": [{}, {}]}}"#, episode("one"), episode("two")))
+    }).await;
+
+    match client.get_episodes("show", None, Some(2), None).run().await.unwrap() {
+        ShowResult::Episodes(episodes) => assert_eq!(episodes.items[1].id, "two"),
+        other => panic!("Expected episodes, got {:?}", other),
+    }
+}
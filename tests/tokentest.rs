@@ -0,0 +1,144 @@
+mod common;
+
+use common::{serve, Reply};
+
+use rustify::Error;
+use rustify::core::auth::{AuthError, JsonFileStore, MemoryStore, Token, TokenProvider, TokenStore};
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+async fn token_server(expires_in: u64) -> (String, Arc<AtomicUsize>) {
+    let hits = Arc::new(AtomicUsize::new(0));
+    let counter = hits.clone();
+    let url = serve(move |request| {
+        assert_eq!(request.form("grant_type").as_deref(), Some("client_credentials"));
+        let count = counter.fetch_add(1, Ordering::SeqCst) + 1;
+        Reply::json(200, &format!(r#"{{"access_token": "token{}", "token_type": "Bearer", "expires_in": {}}}"#, count, expires_in))
+    }).await;
+    (url, hits)
+}
+
+#[tokio::test]
+async fn reuses_token() {
+    let (url, hits) = token_server(3600).await;
+    let provider = TokenProvider::client_credentials("id".to_string(), "secret".to_string()).token_url(&url);
+
+    let token = provider.token().await.unwrap();
+    assert_eq!(token.access_token, "token1");
+    assert_eq!(token.token_type, "Bearer");
+    assert!(token.scopes.is_empty());
+    assert!(token.expires_at > SystemTime::now() + Duration::from_secs(3500));
+
+    // A clone shares the token instead of getting its own
+    assert_eq!(provider.clone().access_token().await.unwrap(), "token1");
+    assert_eq!(hits.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn refreshes_before_expiry() {
+    let (url, hits) = token_server(30).await;
+    let provider = TokenProvider::client_credentials("id".to_string(), "secret".to_string()).token_url(&url);
+
+    // Both tokens are inside of the default 60 second margin so each call gets a new one
+    assert_eq!(provider.access_token().await.unwrap(), "token1");
+    assert_eq!(provider.access_token().await.unwrap(), "token2");
+    assert_eq!(hits.load(Ordering::SeqCst), 2);
+
+    // With a smaller margin the last token is good enough to keep
+    let provider = provider.refresh_margin(Duration::from_secs(10));
+    assert_eq!(provider.access_token().await.unwrap(), "token2");
+    assert_eq!(hits.load(Ordering::SeqCst), 2);
+}
+
+fn user_token(access_token: &str, refresh_token: Option<&str>, expires_in: Duration) -> Token {
+    Token {
+        access_token: access_token.to_string(),
+        token_type: "Bearer".to_string(),
+        scopes: vec!["user-read-private".to_string()],
+        expires_at: SystemTime::now() + expires_in,
+        refresh_token: refresh_token.map(str::to_string),
+    }
+}
+
+#[tokio::test]
+async fn refreshes_user_token_and_saves_it() {
+    let url = serve(|request| {
+        assert_eq!(request.form("grant_type").as_deref(), Some("refresh_token"));
+        assert_eq!(request.form("client_id").as_deref(), Some("id"));
+        match request.form("refresh_token").as_deref() {
+            Some("first") => Reply::json(200, r#"{"access_token": "fresh", "token_type": "Bearer", "scope": "user-read-private", "expires_in": 3600, "refresh_token": "second"}"#),
+            // No new refresh token this time, the old one should be kept
+            Some("second") => Reply::json(200, r#"{"access_token": "fresher", "token_type": "Bearer", "scope": "user-read-private", "expires_in": 30}"#),
+            _ => Reply::json(400, r#"{"error": "invalid_grant"}"#),
+        }
+    }).await;
+
+    let store = Arc::new(MemoryStore::with_token(user_token("stale", Some("first"), Duration::ZERO)));
+    let provider = TokenProvider::user("id".to_string(), store.clone()).token_url(&url);
+
+    assert_eq!(provider.access_token().await.unwrap(), "fresh");
+    assert_eq!(store.load().unwrap().unwrap().refresh_token.as_deref(), Some("second"));
+
+    let provider = provider.refresh_margin(Duration::from_secs(3600));
+    let token = provider.token().await.unwrap();
+    assert_eq!(token.access_token, "fresher");
+    assert_eq!(token.refresh_token.as_deref(), Some("second"));
+    assert_eq!(store.load().unwrap().unwrap(), token);
+}
+
+#[tokio::test]
+async fn user_token_needs_refresh_token() {
+    let provider = TokenProvider::user("id".to_string(), MemoryStore::new());
+    assert!(matches!(provider.token().await, Err(Error::Auth(AuthError::MissingRefreshToken))));
+
+    // A token that is still good is used without a refresh token
+    let provider = TokenProvider::user("id".to_string(), MemoryStore::with_token(user_token("good", None, Duration::from_secs(3600))));
+    assert_eq!(provider.access_token().await.unwrap(), "good");
+}
+
+#[test]
+fn json_file_store() {
+    let path = std::env::temp_dir().join(format!("rustify-token-{}.json", std::process::id()));
+    let store = JsonFileStore::new(&path);
+    assert_eq!(store.load().unwrap(), None);
+
+    let token = user_token("access", Some("refresh"), Duration::from_secs(3600));
+    store.save(&token).unwrap();
+    assert_eq!(store.load().unwrap(), Some(token));
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    // Saving again replaces the token and doesn't leave the temp file behind
+    let newer = user_token("newer", Some("rotated"), Duration::from_secs(3600));
+    store.save(&newer).unwrap();
+    assert_eq!(store.load().unwrap(), Some(newer));
+    assert!(!path.with_file_name(format!("rustify-token-{}.json.tmp", std::process::id())).exists());
+    std::fs::remove_file(path).unwrap();
+}
+
+#[tokio::test]
+async fn token_errors() {
+    let url = serve(|request| match request.form("client_id").as_deref() {
+        Some("bad") => Reply::json(400, r#"{"error": "invalid_client", "error_description": "Invalid client"}"#),
+        _ => Reply::json(200, r#"{"access_token": 12, "token_type": "Bearer", "expires_in": 3600}"#),
+    }).await;
+
+    let provider = TokenProvider::client_credentials("bad".to_string(), "secret".to_string()).token_url(&url);
+    match provider.token().await {
+        Err(err @ Error::Auth(AuthError::Rejected { .. })) => assert_eq!(err.status(), Some(400)),
+        other => panic!("Expected the token to be rejected, got {:?}", other),
+    }
+
+    let provider = TokenProvider::client_credentials("id".to_string(), "secret".to_string()).token_url(&url);
+    match provider.token().await {
+        Err(Error::Decode { path, .. }) => assert_eq!(path, "access_token"),
+        other => panic!("Expected a decode error, got {:?}", other),
+    }
+}
@@ -0,0 +1,133 @@
+mod common;
+
+use common::{api, Reply};
+
+use rustify::core::ratelimits::RateLimiter;
+use rustify::track::{RecommendationsQuery, TrackData, TrackResult, TunableAttribute};
+
+use std::sync::{Arc, Mutex};
+
+fn track(id: &str, linked_from: Option<&str>) -> String {
+    let linked_from = match linked_from {
+        Some(original) => format!(r#"{{"id": "{original}", "type": "track", "uri": "spotify:track:{original}"}}"#),
+        None => "null".to_string(),
+    };
+    format!(r#"{{"album": {{"album_type": "album", "total_tracks": 1, "external_urls": {{"spotify": ""}}, "href": "", "id": "album", "images": [], "name": "Album",
+        "release_date": "2020", "release_date_precision": "year", "type": "album", "uri": "", "artists": []}},
+        "artists": [], "disc_number": 1, "duration_ms": 1000, "explicit": false, "external_ids": {{"isrc": "isrc"}}, "external_urls": {{"spotify": ""}},
+        "href": "", "id": "{id}", "linked_from": {linked_from}, "name": "Track {id}", "popularity": 1, "preview_url": null, "track_number": 1,
+        "type": "track", "uri": "", "is_local": false}}"#)
+}
+
+#[tokio::test]
+async fn get_tracks_in_chunks() {
+    let chunks = Arc::new(Mutex::new(Vec::new()));
+    let seen = chunks.clone();
+    let (url, tokens) = api(move |request| {
+        assert_eq!(request.query("market").as_deref(), Some("US"));
+        let ids = request.query("ids").unwrap();
+        let ids: Vec<&str> = ids.split(',').collect();
+        seen.lock().unwrap().push(ids.len());
+        // track7 isn't playable in the market so spotify relinks it to another version
+        let tracks: Vec<String> = ids.iter().map(|id| if *id == "track7" { track("relinked", Some("track7")) } else { track(id, None) }).collect();
+        Reply::json(200, &format!(r#"{{"tracks": [{}]}}"#, tracks.join(",")))
+    }).await;
+
+    let mut client = TrackData::from_provider(tokens, RateLimiter::new());
+    client.api_url(&url);
+
+    let ids: Vec<String> = (0..120).map(|number| format!("track{}", number)).collect();
+    let ids: Vec<&str> = ids.iter().map(String::as_str).collect();
+
+    match client.get_tracks(&ids, Some("US")).run().await.unwrap() {
+        TrackResult::Tracks(tracks) => {
+            let tracks: Vec<_> = tracks.into_iter().map(Option::unwrap).collect();
+            assert_eq!(tracks.len(), 120);
+            for (id, track) in ids.iter().zip(tracks.iter()) {
+                assert_eq!(track.requested_id(), *id);
+            }
+            assert_eq!(tracks[7].id, "relinked");
+        }
+        other => panic!("Expected tracks, got {:?}", other),
+    }
+
+    let mut chunks = chunks.lock().unwrap().clone();
+    chunks.sort();
+    assert_eq!(chunks, vec![20, 50, 50]);
+}
+
+fn features(id: &str) -> String {
+    format!(r#"{{"acousticness": 0.1, "analysis_url": "", "danceability": 0.8, "duration_ms": 200000, "energy": 0.9, "id": "{id}", "instrumentalness": 0.0,
+        "key": 5, "liveness": 0.1, "loudness": -5.5, "mode": 1, "speechiness": 0.05, "tempo": 124.0, "time_signature": 4, "track_href": "",
+        "type": "audio_features", "uri": "", "valence": 0.7}}"#)
+}
+
+#[tokio::test]
+async fn audio_features_in_chunks_of_100() {
+    let chunks = Arc::new(Mutex::new(Vec::new()));
+    let seen = chunks.clone();
+    let (url, tokens) = api(move |request| {
+        assert!(request.path.starts_with("/audio-features?"));
+        let ids = request.query("ids").unwrap();
+        let ids: Vec<&str> = ids.split(',').collect();
+        seen.lock().unwrap().push(ids.len());
+        let features: Vec<String> = ids.iter().map(|id| if *id == "track3" { "null".to_string() } else { features(id) }).collect();
+        Reply::json(200, &format!(r#"{{"audio_features": [{}]}}"#, features.join(",")))
+    }).await;
+
+    let mut client = TrackData::from_provider(tokens, RateLimiter::new());
+    client.api_url(&url);
+
+    let ids: Vec<String> = (0..150).map(|number| format!("track{}", number)).collect();
+    let ids: Vec<&str> = ids.iter().map(String::as_str).collect();
+    match client.get_several_audio_features(&ids).run().await.unwrap() {
+        TrackResult::SeveralAudioFeatures(features) => {
+            assert_eq!(features.len(), 150);
+            assert!(features[3].is_none());
+            assert_eq!(features[149].as_ref().unwrap().id, "track149");
+            assert_eq!(features[0].as_ref().unwrap().tempo, 124.0);
+        }
+        other => panic!("Expected audio features, got {:?}", other),
+    }
+
+    let mut chunks = chunks.lock().unwrap().clone();
+    chunks.sort();
+    assert_eq!(chunks, vec![50, 100]);
+}
+
+#[tokio::test]
+async fn recommendations() {
+    let (url, tokens) = api(|request| {
+        assert!(request.path.starts_with("/recommendations?"));
+        assert_eq!(request.query("seed_genres").as_deref(), Some("house,techno"));
+        assert_eq!(request.query("seed_tracks").as_deref(), Some("track"));
+        assert_eq!(request.query("min_tempo").as_deref(), Some("120"));
+        assert_eq!(request.query("target_energy").as_deref(), Some("0.8"));
+        assert_eq!(request.query("max_popularity").as_deref(), Some("50"));
+        Reply::json(200, &format!(r#"{{"seeds": [{{"afterFilteringSize": 250, "afterRelinkingSize": 250, "href": null, "id": "house",
+            "initialPoolSize": 250, "type": "GENRE"}}], "tracks": [{}]}}"#, track("recommended", None)))
+    }).await;
+
+    let mut client = TrackData::from_provider(tokens, RateLimiter::new());
+    client.api_url(&url);
+
+    let query = RecommendationsQuery::new()
+        .seed_genres(&["house", "techno"])
+        .seed_tracks(&["track"])
+        .min(TunableAttribute::Tempo, 120.0)
+        .target(TunableAttribute::Energy, 0.8)
+        .max(TunableAttribute::Popularity, 50.0);
+    match client.get_recommendations(query).run().await.unwrap() {
+        TrackResult::Recommendations(recommendations) => {
+            assert_eq!(recommendations.seeds[0].after_filtering_size, 250);
+            assert_eq!(recommendations.tracks[0].id, "recommended");
+        }
+        other => panic!("Expected recommendations, got {:?}", other),
+    }
+
+    // Too many seeds and a min over the max are turned down without sending anything
+    let too_many = RecommendationsQuery::new().seed_artists(&["1", "2", "3"]).seed_tracks(&["4", "5", "6"]);
+    assert!(client.get_recommendations(too_many).run().await.is_err());
+    let backwards = RecommendationsQuery::new().seed_genres(&["house"]).min(TunableAttribute::Energy, 0.9).max(TunableAttribute::Energy, 0.1);
+    assert!(client.get_recommendations(backwards).run().await.is_err());
+}
@@ -0,0 +1,33 @@
+mod common;
+
+use common::{api, Reply};
+
+use rustify::core::ratelimits::RateLimiter;
+use rustify::user::UserData;
+
+#[tokio::test]
+async fn current_and_public_profiles() {
+    let (url, tokens) = api(|request| match request.path.as_str() {
+        "/me" => Reply::json(200, r#"{"country": "NZ", "display_name": "Me", "email": "me@example.com",
+            "explicit_content": {"filter_enabled": true, "filter_locked": false}, "external_urls": {"spotify": ""},
+            "followers": {"href": null, "total": 3}, "href": "", "id": "me", "images": [{"url": "small", "height": 64, "width": 64},
+            {"url": "big", "height": 300, "width": 300}], "product": "premium", "type": "user", "uri": "spotify:user:me"}"#),
+        "/users/someone" => Reply::json(200, r#"{"display_name": null, "external_urls": {"spotify": ""}, "followers": {"href": null, "total": 0},
+            "href": "", "id": "someone", "images": [], "type": "user", "uri": "spotify:user:someone"}"#),
+        path => panic!("Unexpected request to {}", path),
+    }).await;
+
+    let mut client = UserData::from_provider(tokens, RateLimiter::new());
+    client.api_url(&url);
+
+    let me = client.get_current_user().run().await.unwrap();
+    assert_eq!(me.country.as_deref(), Some("NZ"));
+    assert_eq!(me.email.as_deref(), Some("me@example.com"));
+    assert!(me.explicit_content.unwrap().filter_enabled);
+    assert_eq!(me.images.len(), 2);
+    assert_eq!(me.followers.href, None);
+
+    let someone = client.get_user("someone").run().await.unwrap();
+    assert_eq!(someone.product, None);
+    assert!(someone.images.is_empty());
+}